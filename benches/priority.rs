@@ -0,0 +1,33 @@
+//! How much the common operations cost, to see what polling a priority in
+//! a loop would pay for.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use scrummage::Process;
+
+fn current(c: &mut Criterion) {
+    c.bench_function("Process::current", |b| b.iter(Process::current));
+}
+
+fn priority(c: &mut Criterion) {
+    let me = Process::current();
+    c.bench_function("Process::priority", |b| b.iter(|| me.priority().unwrap()));
+    c.bench_function("Process::current().priority", |b| {
+        b.iter(|| Process::current().priority().unwrap())
+    });
+}
+
+fn set_priority(c: &mut Criterion) {
+    let mut me = Process::current();
+    // Reapply the priority we already have, so the benchmark doesn't need
+    // privileges and leaves the process as it found it
+    let priority = me.priority().unwrap();
+    c.bench_function("Process::set_priority", |b| {
+        b.iter(|| me.set_priority(black_box(priority)).unwrap())
+    });
+    c.bench_function("Process::set_priority_if_changed", |b| {
+        b.iter(|| me.set_priority_if_changed(black_box(priority)).unwrap())
+    });
+}
+
+criterion_group!(benches, current, priority, set_priority);
+criterion_main!(benches);
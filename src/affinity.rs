@@ -0,0 +1,92 @@
+const WORD: usize = 64;
+
+/// A set of CPU cores, identified by their index.
+///
+/// The [affinity](crate::Process::set_affinity) of a process is the set of
+/// cores it's allowed to run on.
+///
+/// ```rust
+/// # use scrummage::CpuSet;
+/// let mut set: CpuSet = (0..4).collect();
+/// set.remove(2);
+/// assert!(set.contains(3));
+/// assert_eq!(set.iter().collect::<Vec<_>>(), [0, 1, 3]);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CpuSet {
+    bits: [u64; CpuSet::CAPACITY / WORD],
+}
+
+impl CpuSet {
+    /// The number of cores a set can refer to.
+    ///
+    /// Cores `0` up to, but not including, `CAPACITY` can be stored.
+    pub const CAPACITY: usize = 1024;
+
+    /// Create a set with no cores in it
+    pub const fn new() -> Self {
+        Self {
+            bits: [0; Self::CAPACITY / WORD],
+        }
+    }
+    /// Add a core to the set, returning whether it was newly added.
+    ///
+    /// # Panics
+    ///
+    /// If `cpu` is not less than [`CAPACITY`](CpuSet::CAPACITY).
+    pub fn insert(&mut self, cpu: usize) -> bool {
+        assert!(cpu < Self::CAPACITY, "CPU {} is out of range", cpu);
+        let added = !self.contains(cpu);
+        self.bits[cpu / WORD] |= 1 << (cpu % WORD);
+        added
+    }
+    /// Remove a core from the set, returning whether it was present.
+    pub fn remove(&mut self, cpu: usize) -> bool {
+        let present = self.contains(cpu);
+        if present {
+            self.bits[cpu / WORD] &= !(1 << (cpu % WORD));
+        }
+        present
+    }
+    /// Whether the set contains a core
+    pub fn contains(&self, cpu: usize) -> bool {
+        cpu < Self::CAPACITY && self.bits[cpu / WORD] & (1 << (cpu % WORD)) != 0
+    }
+    /// The number of cores in the set
+    pub fn len(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+    /// Whether the set has no cores in it
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|&word| word == 0)
+    }
+    /// Iterate over the cores in the set, in increasing order
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..Self::CAPACITY).filter(move |&cpu| self.contains(cpu))
+    }
+}
+
+impl core::fmt::Debug for CpuSet {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl Extend<usize> for CpuSet {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for cpu in iter {
+            self.insert(cpu);
+        }
+    }
+}
+
+impl core::iter::FromIterator<usize> for CpuSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
@@ -1,63 +1,156 @@
 //! A sketchy implementation of the `nice` utility built on `scrummage`.
-use scrummage::{Priority, Process};
+use scrummage::{Priority, PriorityLevel, Process, Unchanged};
+use std::convert::TryFrom;
+use std::ffi::OsString;
 use std::process::Command;
 
-macro_rules! fail {
-    ($n:literal : $fmt:literal $(, $t:expr)*) => {|| {
-        // TODO: Fill help message
-        eprintln!(concat!("help blah blah\n", $fmt) $(, $t)*);
-        std::process::exit($n);
-    }}
+const USAGE: &str = "\
+usage: nice [-n increment | --priority level] utility [argument...]
+       nice (-n increment | --priority level) --pid pid
+
+Run `utility` with a different priority, or change the priority of a
+running process.
+
+    -n increment        Use the priority `increment` steps below normal, or
+                        above it if `increment` is negative. Out of range
+                        increments are clamped to the lowest and highest
+                        priorities.
+    --priority level    Use a named priority: one of `idle` (or
+                        `background`), `low`, `normal`, `high` or
+                        `realtime`.
+    --pid pid           Change the priority of the process `pid` instead of
+                        running a `utility`. Exits with 2 if it couldn't be
+                        found, and 3 if its priority couldn't be changed.";
+
+/// The parsed command line
+struct Args {
+    priority: Option<Priority>,
+    target: Target,
+}
+
+/// The process to set the priority of
+enum Target {
+    Spawn {
+        utility: OsString,
+        arguments: std::env::ArgsOs,
+    },
+    Pid(u32, Priority),
+}
+
+fn parse_args(mut args: std::env::ArgsOs) -> Result<Args, String> {
+    args.next();
+    let mut priority = None;
+    let mut pid = None;
+    let utility = loop {
+        let arg = match args.next() {
+            Some(arg) => arg,
+            None => break None,
+        };
+        match arg.to_str() {
+            Some("-n") => {
+                let arg = args.next().ok_or("expected an `increment`")?;
+                let increment = arg
+                    .to_str()
+                    .and_then(|s| s.parse().ok())
+                    .ok_or_else(|| format!("{:?} is not an `increment`", arg))?;
+                priority = Some(step(increment));
+            }
+            Some("--priority") => {
+                let arg = args.next().ok_or("expected a `level`")?;
+                let level = arg
+                    .to_str()
+                    .and_then(|s| s.parse::<PriorityLevel>().ok())
+                    .ok_or_else(|| format!("{:?} is not a `level`", arg))?;
+                priority = Some(level.into());
+            }
+            Some("--pid") => {
+                let arg = args.next().ok_or("expected a `pid`")?;
+                let id = arg
+                    .to_str()
+                    .and_then(|s| s.parse().ok())
+                    .ok_or_else(|| format!("{:?} is not a `pid`", arg))?;
+                pid = Some(id);
+            }
+            _ => break Some(arg),
+        }
+    };
+    let target = match (pid, utility) {
+        (Some(_), Some(utility)) => {
+            return Err(format!("unexpected {:?} after a `pid`", utility));
+        }
+        (Some(pid), None) => match priority {
+            Some(priority) => Target::Pid(pid, priority),
+            None => return Err("expected an `increment` or `level` for the `pid`".into()),
+        },
+        (None, Some(utility)) => Target::Spawn {
+            utility,
+            arguments: args,
+        },
+        (None, None) => return Err("expected a `utility`".into()),
+    };
+    Ok(Args { priority, target })
+}
+
+/// The priority `increment` steps below normal, clamped to the available levels
+fn step(increment: i64) -> Priority {
+    let steps = usize::try_from(increment.unsigned_abs()).unwrap_or(usize::MAX);
+    if increment >= 0 {
+        Priority::normal().saturating_lower(steps)
+    } else {
+        Priority::normal().saturating_higher(steps)
+    }
 }
 
 fn main() {
-    let mut args = std::env::args_os();
-    let first = args.nth(1).unwrap_or_else(fail!(1: "expected a `utility`"));
-    let mut child = if let Some("-n") = first.to_str() {
-        let arg = args
-            .next()
-            .unwrap_or_else(fail!(1: "expected an `increment`"));
-        let priority = arg
-            .to_str()
-            .and_then(|s| s.parse().ok())
-            .map(|n: i64| {
-                if n >= 0 {
-                    Priority::normal()
-                        .lower()
-                        .take(n as usize)
-                        .last()
-                        .unwrap_or(Priority::normal())
-                } else {
-                    Priority::normal()
-                        .higher()
-                        .take(-n as usize)
-                        .last()
-                        .unwrap_or(Priority::normal())
-                }
+    let Args { priority, target } = parse_args(std::env::args_os()).unwrap_or_else(|message| {
+        eprintln!("nice: {}\n\n{}", message, USAGE);
+        std::process::exit(1);
+    });
+    let (utility, arguments) = match target {
+        Target::Spawn { utility, arguments } => (utility, arguments),
+        Target::Pid(pid, priority) => std::process::exit(renice(pid, priority)),
+    };
+    let mut child = Command::new(&utility)
+        .args(arguments)
+        .spawn()
+        .unwrap_or_else(|e| {
+            eprintln!("nice: couldn't run {:?}: {}", utility, e);
+            std::process::exit(127);
+        });
+    let status = match priority {
+        Some(priority) => {
+            Process::wait_at_priority(&mut child, priority, || {}).unwrap_or_else(|e| {
+                eprintln!("nice: failed to set priority: {}", e);
+                child.wait()
             })
-            .unwrap_or_else(fail!(1: "{:?} is not an `increment`", arg));
-
-        let cmd = args.next().unwrap_or_else(fail!(1: "expected a `utility`"));
-        let mut child = Command::new(&cmd)
-            .args(args)
-            .spawn()
-            .ok()
-            .unwrap_or_else(fail!(127: "something went wrong while running {:?}", cmd));
-        if let Err(e) = Process::from(&mut child).set_priority(priority) {
-            eprintln!("Failed to set priority: {}", e);
         }
-        child
-    } else {
-        Command::new(&first)
-            .args(args)
-            .spawn()
-            .ok()
-            .unwrap_or_else(fail!(127: "something went wrong while running {:?}", first))
+        None => child.wait(),
     };
-    let n = child
-        .wait()
-        // TODO: Propagate signals
-        .map(|status| status.code().unwrap())
-        .unwrap();
-    std::process::exit(n);
+    let status = status.unwrap_or_else(|e| {
+        eprintln!("nice: couldn't wait for {:?}: {}", utility, e);
+        std::process::exit(1);
+    });
+    std::process::exit(scrummage::exit_status_to_code(&status));
+}
+
+/// Set the priority of the running process `pid`, returning the code to exit with
+fn renice(pid: u32, priority: Priority) -> i32 {
+    match Process::from_pid(pid).swap_priority(priority) {
+        Ok(old) => {
+            println!("{}: old priority {}, new priority {}", pid, old, priority);
+            0
+        }
+        Err(e @ Unchanged::NotFound(_)) => {
+            eprintln!("nice: {}: {}", pid, e);
+            2
+        }
+        Err(e @ Unchanged::PermissionDenied) | Err(e @ Unchanged::NiceLimit(_)) => {
+            eprintln!("nice: {}: {}", pid, e);
+            3
+        }
+        Err(e) => {
+            eprintln!("nice: {}: {}", pid, e);
+            1
+        }
+    }
 }
@@ -0,0 +1,78 @@
+use crate::Priority;
+
+/// A named priority, with the same meaning on every platform.
+///
+/// This is a small, stable vocabulary for config files and command lines,
+/// which [converts](Priority::from) to the nearest level available on the
+/// platform. Names are parsed case-insensitively:
+///
+/// ```rust
+/// # use scrummage::{Priority, PriorityLevel};
+/// let level: PriorityLevel = "background".parse().unwrap();
+/// assert_eq!(level, PriorityLevel::Idle);
+/// assert_eq!(Priority::from(level), Priority::lowest());
+/// assert!("HIGH".parse::<PriorityLevel>().is_ok());
+/// assert!("urgent".parse::<PriorityLevel>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PriorityLevel {
+    /// Only run when nothing else wants to: the [lowest](Priority::lowest)
+    /// priority. Parsed from `idle` or `background`.
+    Idle,
+    /// One step below [normal](Priority::normal). Parsed from `low`.
+    Low,
+    /// The [normal](Priority::normal) priority. Parsed from `normal`.
+    Normal,
+    /// One step above [normal](Priority::normal). Parsed from `high`.
+    High,
+    /// The [highest](Priority::highest) priority. Parsed from `realtime`.
+    ///
+    /// Niceness is never [real-time](Priority::is_realtime) on Unix, so this
+    /// is just the highest niceness there.
+    Realtime,
+}
+
+impl From<PriorityLevel> for Priority {
+    fn from(level: PriorityLevel) -> Self {
+        let normal = Priority::normal();
+        match level {
+            PriorityLevel::Idle => Priority::lowest(),
+            PriorityLevel::Low => normal.saturating_lower(1),
+            PriorityLevel::Normal => normal,
+            PriorityLevel::High => normal.saturating_higher(1),
+            PriorityLevel::Realtime => Priority::highest(),
+        }
+    }
+}
+
+impl core::str::FromStr for PriorityLevel {
+    type Err = UnknownLevel;
+    fn from_str(name: &str) -> Result<Self, UnknownLevel> {
+        let levels = [
+            ("idle", Self::Idle),
+            ("background", Self::Idle),
+            ("low", Self::Low),
+            ("normal", Self::Normal),
+            ("high", Self::High),
+            ("realtime", Self::Realtime),
+        ];
+        levels
+            .iter()
+            .find(|(known, _)| known.eq_ignore_ascii_case(name))
+            .map(|&(_, level)| level)
+            .ok_or(UnknownLevel)
+    }
+}
+
+/// A name wasn't one of the [`PriorityLevel`]s.
+#[derive(Debug)]
+pub struct UnknownLevel;
+
+impl core::fmt::Display for UnknownLevel {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("unknown priority level")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownLevel {}
@@ -24,25 +24,50 @@
 macro_rules! doctest {
     ($x:expr) => {
         #[doc = $x]
-        extern {}
+        #[cfg(doctest)]
+        pub struct ReadmeDoctests;
     };
 }
 doctest!(include_str!("../README.md"));
 
 #[cfg_attr(unix, path = "./unix.rs")]
+#[cfg_attr(not(unix), path = "./unsupported.rs")]
 mod imp;
+pub mod os;
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+mod affinity;
+pub use affinity::CpuSet;
+mod level;
+pub use level::{PriorityLevel, UnknownLevel};
+mod policy;
+pub use policy::PriorityPolicy;
+mod scheduler;
+pub use scheduler::{Applied, Scheduler};
+
+#[cfg(all(unix, feature = "nix"))]
+mod nix_impl;
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 /// A prioritisation level
 ///
 /// The priority of a [`Process`] controls how much CPU time it gets
 /// compared to other processes. Most programs don't need to be handled
 /// especially, and should be given a [normal](Priority::normal) priority
 /// to allow the OS to handle scheduling
+///
+/// Priorities are ordered by importance, so higher priorities compare as
+/// greater:
+///
+/// ```rust
+/// # use scrummage::Priority;
+/// assert!(Priority::highest() > Priority::normal());
+/// assert!(Priority::lowest() < Priority::normal());
+/// ```
 pub struct Priority(imp::Priority);
 
 impl Priority {
-    // TODO: consider declaring these as `const fn`
     /// The priority level given to normal processes; The default priority
     /// level.
     ///
@@ -51,33 +76,424 @@ impl Priority {
     /// assert_eq!(Process::current().priority().unwrap(), Priority::normal(),
     ///            "I'm normal! Normal I tell you!");
     /// ```
-    pub fn normal() -> Self {
+    pub const fn normal() -> Self {
         Self(imp::Priority::normal())
     }
+    /// The highest priority level available on this platform.
+    ///
+    /// This is the last level yielded by [`higher`](Priority::higher).
+    ///
+    /// ```rust
+    /// # use scrummage::Priority;
+    /// assert_eq!(Priority::normal().higher().last(), Some(Priority::highest()));
+    /// ```
+    pub const fn highest() -> Self {
+        Self(imp::Priority::highest())
+    }
+    /// The lowest priority level available on this platform.
+    ///
+    /// This is the last level yielded by [`lower`](Priority::lower).
+    ///
+    /// ```rust
+    /// # use scrummage::Priority;
+    /// assert_eq!(Priority::normal().lower().last(), Some(Priority::lowest()));
+    /// ```
+    pub const fn lowest() -> Self {
+        Self(imp::Priority::lowest())
+    }
+    /// The number of distinct priority levels on this platform.
+    ///
+    /// ```rust
+    /// # use scrummage::Priority;
+    /// # #[cfg(unix)]
+    /// assert_eq!(Priority::levels(), 40);
+    /// ```
+    pub const fn levels() -> usize {
+        imp::Priority::levels()
+    }
+    /// Every priority level on this platform, from the
+    /// [lowest](Priority::lowest) to the [highest](Priority::highest).
+    ///
+    /// ```rust
+    /// # use scrummage::Priority;
+    /// let mut all = Priority::all();
+    /// assert_eq!(all.len(), Priority::levels());
+    /// assert_eq!(all.next(), Some(Priority::lowest()));
+    /// assert_eq!(all.next_back(), Some(Priority::highest()));
+    /// ```
+    pub fn all() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        imp::Priority::all().map(Self)
+    }
+    /// Every priority level from `low` up to and including `high`.
+    ///
+    /// This is empty if `low` is higher than `high`.
+    ///
+    /// ```rust
+    /// # use scrummage::Priority;
+    /// assert_eq!(Priority::range(Priority::lowest(), Priority::highest()).len(), Priority::levels());
+    /// let normal = Priority::normal();
+    /// assert!(Priority::range(normal, normal).eq([normal]));
+    /// assert_eq!(Priority::range(Priority::highest(), normal).len(), 0);
+    /// ```
+    pub fn range(
+        low: Self,
+        high: Self,
+    ) -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        // The number of levels below each end is its position in `all`
+        let start = low.lower().len();
+        let end = high.lower().len() + 1;
+        Self::all().skip(start).take(end.saturating_sub(start))
+    }
+    /// The priority nearest to a point on a scale from `0.0` to `1.0`.
+    ///
+    /// See [`to_normalized`](Priority::to_normalized) for how the scale is
+    /// laid out. Values outside of the scale are clamped to it, and `NaN` is
+    /// treated as `0.5`, the normal priority.
+    ///
+    /// ```rust
+    /// # use scrummage::Priority;
+    /// assert_eq!(Priority::from_normalized(0.0), Priority::lowest());
+    /// assert_eq!(Priority::from_normalized(0.5), Priority::normal());
+    /// assert_eq!(Priority::from_normalized(2.0), Priority::highest());
+    ///
+    /// // Every level survives the round trip
+    /// for level in Priority::lowest().higher() {
+    ///     assert_eq!(Priority::from_normalized(level.to_normalized()), level);
+    /// }
+    /// ```
+    pub fn from_normalized(scale: f32) -> Self {
+        let scale = if scale.is_nan() {
+            0.5
+        } else {
+            scale.clamp(0.0, 1.0)
+        };
+        Self(imp::Priority::from_normalized(scale))
+    }
+    /// Place this priority on a platform-independent scale from `0.0` to
+    /// `1.0`.
+    ///
+    /// [`lowest`](Priority::lowest) is `0.0`, [`normal`](Priority::normal)
+    /// is `0.5`, and [`highest`](Priority::highest) is `1.0`. The levels
+    /// below normal are spread evenly over the bottom half of the scale, and
+    /// the levels above it over the top half. On Unix, that means each
+    /// niceness step below normal is `0.5 / 19` and each step above it is
+    /// `0.5 / 20`.
+    ///
+    /// This makes it possible to store a priority that's meaningful on every
+    /// platform, such as in a config file.
+    ///
+    /// ```rust
+    /// # use scrummage::Priority;
+    /// let background = Priority::from_normalized(0.25);
+    /// assert!(background < Priority::normal());
+    /// assert!(background.to_normalized() < 0.5);
+    /// ```
+    pub fn to_normalized(&self) -> f32 {
+        self.0.to_normalized()
+    }
+    /// The number of steps this priority is below [normal](Priority::normal).
+    ///
+    /// Higher priorities are negative, so this is a single comparable number
+    /// that's handy for logging. It's the niceness on Unix.
+    ///
+    /// ```rust
+    /// # use scrummage::Priority;
+    /// assert_eq!(Priority::normal().as_step(), 0);
+    /// assert_eq!(Priority::normal().lower().next().unwrap().as_step(), 1);
+    /// assert!(Priority::highest().as_step() < 0);
+    /// ```
+    pub fn as_step(&self) -> i32 {
+        self.0.as_step()
+    }
+    /// The priority `step` levels below [normal](Priority::normal), or above
+    /// it if `step` is negative.
+    ///
+    /// Steps past the [lowest](Priority::lowest) or
+    /// [highest](Priority::highest) levels are clamped to them.
+    ///
+    /// ```rust
+    /// # use scrummage::Priority;
+    /// assert_eq!(Priority::from_step(0), Priority::normal());
+    /// assert_eq!(Priority::from_step(i32::MAX), Priority::lowest());
+    /// assert_eq!(Priority::from_step(i32::MIN), Priority::highest());
+    /// let p = Priority::normal().higher().next().unwrap();
+    /// assert_eq!(Priority::from_step(p.as_step()), p);
+    /// ```
+    pub fn from_step(step: i32) -> Self {
+        Self(imp::Priority::from_step(step))
+    }
+    /// Whether this is a real-time priority.
+    ///
+    /// Real-time processes run before everything else, and can freeze the
+    /// whole machine if they never stop for a break. Niceness values are
+    /// never real-time on Unix; see
+    /// [`SchedPolicy`](crate::os::linux::SchedPolicy) on Linux instead.
+    pub fn is_realtime(&self) -> bool {
+        self.0.is_realtime()
+    }
+    /// How this priority compares to [normal](Priority::normal).
+    ///
+    /// This is `Greater` for higher priorities, and `Less` for lower ones.
+    ///
+    /// ```rust
+    /// # use scrummage::Priority;
+    /// use std::cmp::Ordering;
+    /// assert_eq!(Priority::highest().relative_to_normal(), Ordering::Greater);
+    /// assert_eq!(Priority::normal().relative_to_normal(), Ordering::Equal);
+    /// assert_eq!(Priority::lowest().relative_to_normal(), Ordering::Less);
+    /// ```
+    pub fn relative_to_normal(&self) -> core::cmp::Ordering {
+        self.cmp(&Self::normal())
+    }
+    /// Whether this priority is higher than [normal](Priority::normal).
+    ///
+    /// ```rust
+    /// # use scrummage::Priority;
+    /// assert!(Priority::highest().is_elevated());
+    /// assert!(!Priority::normal().is_elevated());
+    /// ```
+    pub fn is_elevated(&self) -> bool {
+        self.0.is_elevated()
+    }
+    /// Whether this is the [lowest](Priority::lowest) priority, which only
+    /// runs when nothing else wants to.
+    ///
+    /// ```rust
+    /// # use scrummage::Priority;
+    /// assert!(Priority::lowest().is_idle());
+    /// // Unless normal is the only level, as on unsupported platforms
+    /// # #[cfg(unix)]
+    /// assert!(!Priority::normal().is_idle());
+    /// ```
+    pub fn is_idle(&self) -> bool {
+        self.0.is_idle()
+    }
     /// Raise the priority level.
     ///
     /// Be particularly careful with giving processes higher priority levels:
     /// Any process with a lower level will be halted until it pauses.
     /// Therefore, make sure any work it does is breif, and it uses OS APIs for
     /// delays ([`std::thread::sleep`] instead of `loop {}`)
-    pub fn higher(&self) -> impl Iterator<Item = Self> {
+    ///
+    /// The levels are yielded in increasing order, so walking the iterator
+    /// backwards starts from [`highest`](Priority::highest).
+    ///
+    /// ```rust
+    /// # use scrummage::Priority;
+    /// assert_eq!(Priority::normal().higher().next_back(), Some(Priority::highest()));
+    /// assert_eq!(Priority::highest().higher().len(), 0);
+    /// ```
+    pub fn higher(&self) -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
         self.0.higher().map(Self)
     }
+    /// Raise the priority level by `steps`, stopping at the
+    /// [highest](Priority::highest) level.
+    ///
+    /// ```rust
+    /// # use scrummage::Priority;
+    /// let normal = Priority::normal();
+    /// assert_eq!(normal.saturating_higher(0), normal);
+    /// assert_eq!(normal.saturating_higher(2), normal.higher().nth(1).unwrap());
+    /// assert_eq!(normal.saturating_higher(usize::MAX), Priority::highest());
+    /// ```
+    pub fn saturating_higher(&self, steps: usize) -> Self {
+        self.higher().take(steps).next_back().unwrap_or(*self)
+    }
+    /// Lower the priority level by `steps`, stopping at the
+    /// [lowest](Priority::lowest) level.
+    ///
+    /// ```rust
+    /// # use scrummage::Priority;
+    /// let normal = Priority::normal();
+    /// assert_eq!(normal.saturating_lower(0), normal);
+    /// assert_eq!(normal.saturating_lower(2), normal.lower().nth(1).unwrap());
+    /// assert_eq!(normal.saturating_lower(usize::MAX), Priority::lowest());
+    /// ```
+    pub fn saturating_lower(&self, steps: usize) -> Self {
+        self.lower().take(steps).next_back().unwrap_or(*self)
+    }
+    /// Find the closest priority to this one that `process` can be given
+    ///
+    /// Raising a priority usually needs privileges, so this tries each level
+    /// from this one down to the process's current priority, stopping at the
+    /// first one that's allowed. Each try sets the priority and then puts it
+    /// back, so the process ends up with the priority it started with.
+    ///
+    /// Priorities at or below the current one are returned without being
+    /// tried, since lowering the priority of your own processes is always
+    /// allowed.
+    ///
+    /// ```rust
+    /// # use scrummage::{Priority, Process};
+    /// let mut me = Process::current();
+    /// let before = me.priority().unwrap();
+    /// let best = Priority::highest().nearest_allowed(&mut me).unwrap();
+    /// assert!(best >= before);
+    /// assert_eq!(me.priority().unwrap(), before);
+    /// ```
+    pub fn nearest_allowed(&self, process: &mut Process<'_>) -> Result<Self, Unchanged> {
+        let current = process.priority()?;
+        let mut candidate = *self;
+        while candidate > current {
+            match process.with_priority(candidate) {
+                Ok(guard) => {
+                    guard.restore_now()?;
+                    return Ok(candidate);
+                }
+                Err(Unchanged::PermissionDenied) | Err(Unchanged::NiceLimit(_)) => {
+                    candidate = candidate.saturating_lower(1)
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(candidate)
+    }
     /// Lower the priority level.
     ///
     /// Processes with lower priority levels will pause if other processes need
     /// to do work. They can be used for screen-savers e.t.c.
-    pub fn lower(&self) -> impl Iterator<Item = Self> {
+    ///
+    /// The levels are yielded in decreasing order, so walking the iterator
+    /// backwards starts from [`lowest`](Priority::lowest).
+    ///
+    /// ```rust
+    /// # use scrummage::Priority;
+    /// assert_eq!(Priority::lowest().lower().len(), 0);
+    /// ```
+    pub fn lower(&self) -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
         self.0.lower().map(Self)
     }
 }
 
+/// Give up the rest of this thread's time slice, letting the OS run
+/// something else.
+///
+/// This is useful after raising the priority of another process, to give it
+/// a turn straight away. If nothing else wants to run, this returns
+/// immediately.
+pub fn yield_now() {
+    imp::yield_now()
+}
+
+/// Whether this process is allowed to raise its own priority
+///
+/// On Linux that takes the `CAP_SYS_NICE` capability, which root has, or a
+/// `RLIMIT_NICE` resource limit above the current priority. Use this to
+/// avoid offering to raise a priority when it would only fail with
+/// [`Unchanged::PermissionDenied`].
+///
+/// ```rust
+/// # use scrummage::Process;
+/// let me = Process::current();
+/// let reachable = me.reachable_priorities().unwrap();
+/// if let Some(higher) = me.priority().unwrap().higher().next() {
+///     assert_eq!(scrummage::can_raise_priority(), reachable.contains(&higher));
+/// }
+/// ```
+#[cfg(all(target_os = "linux", feature = "std"))]
+pub fn can_raise_priority() -> bool {
+    imp::can_raise_priority()
+}
+
+/// Update the priority of many processes at once
+///
+/// Every process is updated, even if some fail, and the result for each
+/// process is returned in the same order as `processes`.
+///
+/// ```rust
+/// # use scrummage::{Priority, Process};
+/// let mut workers = [Process::current(), Process::from_pid(u32::MAX)];
+/// let results = scrummage::set_priority_all(&mut workers, Priority::lowest());
+/// assert!(results[0].is_ok());
+/// assert!(results[1].is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn set_priority_all(
+    processes: &mut [Process<'_>],
+    priority: Priority,
+) -> Vec<Result<(), Unchanged>> {
+    processes
+        .iter_mut()
+        .map(|process| process.set_priority(priority))
+        .collect()
+}
+
+/// Spawn `command` with `priority` already set
+///
+/// Setting the priority of a [`Child`](std::process::Child) after spawning
+/// it leaves a moment where it runs at the default priority, which can be
+/// most of the life of a short child. On Unix, this sets the priority in
+/// the child just before it runs the program, and reports failure as
+/// failing to spawn. Like [`Process::set_priority`],
+/// [real-time](Priority::is_realtime) priorities are refused.
+///
+/// This takes `command` by value because the hook that sets the priority
+/// can't be removed, so reusing it would set every earlier priority too.
+///
+/// ```rust
+/// # use scrummage::{Priority, Process};
+/// let mut command = std::process::Command::new("sleep");
+/// command.arg("1");
+/// let mut child = scrummage::spawn_with_priority(command, Priority::lowest()).unwrap();
+/// assert_eq!(Process::of_child(&mut child).priority().unwrap(), Priority::lowest());
+/// # child.kill().unwrap();
+/// # child.wait().unwrap();
+/// ```
+#[cfg(feature = "std")]
+pub fn spawn_with_priority(
+    command: std::process::Command,
+    priority: Priority,
+) -> std::io::Result<std::process::Child> {
+    if priority.is_realtime() {
+        return Err(Unchanged::Realtime.into());
+    }
+    imp::spawn_with_priority(command, priority.0)
+}
+
+/// The code a shell would report for a child that exited with `status`
+///
+/// This is the exit code when there is one. On Unix, a child killed by a
+/// signal has no exit code, so this follows the shell convention of
+/// `128 + signal`. Anything else gives `1`.
+///
+/// ```rust
+/// # use std::process::Command;
+/// let status = Command::new("sh").args(&["-c", "exit 3"]).status().unwrap();
+/// assert_eq!(scrummage::exit_status_to_code(&status), 3);
+///
+/// let mut child = Command::new("sleep").arg("10").spawn().unwrap();
+/// child.kill().unwrap();
+/// # #[cfg(unix)]
+/// assert_eq!(scrummage::exit_status_to_code(&child.wait().unwrap()), 128 + 9);
+/// ```
+#[cfg(feature = "std")]
+pub fn exit_status_to_code(status: &std::process::ExitStatus) -> i32 {
+    if let Some(code) = status.code() {
+        return code;
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        if let Some(signal) = status.signal() {
+            return 128 + signal;
+        }
+    }
+    1
+}
+
 #[derive(Debug)]
 /// A process running on this machine.
 ///
 /// Because the OS owns the process this "refers" to, we can't know it's valid:
 /// someone could've killed it. Therefore, the methods return [`NotFound`] if
 /// they are ever called on a dead process.
+///
+/// A `Process` is `Send` and `Sync`, so it can be moved into other threads
+/// and async tasks. Other than [`wait_at_priority`], which waits for a
+/// child to exit, its methods don't block: they make a few quick syscalls,
+/// and the Linux ones that read `/proc` or `/sys` only read in-memory files.
+///
+/// [`wait_at_priority`]: Process::wait_at_priority
 pub struct Process<'a>(imp::Process<'a>);
 
 impl Process<'_> {
@@ -86,37 +502,898 @@ impl Process<'_> {
     /// Note that this is will last for `'static`, since the OS process it
     /// refers to contains this very struct, and if it died, then this struct
     /// must have died with it.
+    ///
+    /// This makes a syscall on Unix to find the pid, which costs about as
+    /// much as reading the priority. Keep the `Process` around instead of
+    /// calling this again when polling in a loop.
     pub fn current() -> Process<'static> {
         Process(imp::Process::current())
     }
+    /// Get the currently running process, reporting failure like any other
+    /// lookup would
+    ///
+    /// This always succeeds today, but gives generic code one fallible
+    /// signature for every way of getting a `Process`.
+    ///
+    /// ```rust
+    /// # use scrummage::Process;
+    /// let me = Process::try_current().unwrap();
+    /// assert_eq!(me.id(), std::process::id());
+    /// ```
+    pub fn try_current() -> Result<Process<'static>, Unchanged> {
+        Ok(Process::current())
+    }
+    /// Refer to an arbitrary process by its ID
+    ///
+    /// The process isn't checked to exist: like any other [`Process`], the
+    /// methods will return [`NotFound`] once it's gone. An ID of 0 is never
+    /// found, even though some syscalls read it as the calling process.
+    ///
+    /// ```rust
+    /// # use scrummage::Process;
+    /// let me = Process::from_pid(std::process::id());
+    /// assert_eq!(me.priority().unwrap(), Process::current().priority().unwrap());
+    /// ```
+    pub fn from_pid(pid: u32) -> Process<'static> {
+        Process(imp::Process::from_pid(pid))
+    }
+    /// Refer to a process by its ID, checking that it's running now
+    ///
+    /// The process can still exit afterwards, so later methods may return
+    /// [`NotFound`] all the same. See [`exists`](Process::exists) for what
+    /// counts as running.
+    ///
+    /// ```rust
+    /// # use scrummage::Process;
+    /// assert!(Process::try_from_pid(std::process::id()).is_ok());
+    /// assert!(Process::try_from_pid(0).is_err());
+    ///
+    /// let mut child = std::process::Command::new("true").spawn().unwrap();
+    /// let id = child.id();
+    /// child.wait().unwrap();
+    /// assert!(Process::try_from_pid(id).is_err());
+    /// ```
+    pub fn try_from_pid(pid: u32) -> Result<Process<'static>, NotFound> {
+        let process = Process::from_pid(pid);
+        if process.exists() {
+            Ok(process)
+        } else {
+            Err(NotFound)
+        }
+    }
+    /// The OS identifier of this process
+    ///
+    /// ```rust
+    /// # use scrummage::Process;
+    /// let mut child = std::process::Command::new("sleep").arg("1").spawn().unwrap();
+    /// let id = child.id();
+    /// assert_eq!(Process::of_child(&mut child).id(), id);
+    /// assert_eq!(Process::from_pid(id).id(), id);
+    /// # child.kill().unwrap();
+    /// # child.wait().unwrap();
+    /// ```
+    pub fn id(&self) -> u32 {
+        self.0.id()
+    }
+    /// Check whether this process is still running
+    ///
+    /// A child process that has exited is still considered to exist until it
+    /// has been waited on.
+    ///
+    /// ```rust
+    /// # use scrummage::Process;
+    /// assert!(Process::current().exists());
+    ///
+    /// let mut child = std::process::Command::new("true").spawn().unwrap();
+    /// let id = child.id();
+    /// child.wait().unwrap();
+    /// assert!(!Process::from_pid(id).exists());
+    /// ```
+    pub fn exists(&self) -> bool {
+        self.0.exists()
+    }
     /// Update the priority of this process
-
+    ///
+    /// [Real-time](Priority::is_realtime) priorities are refused with
+    /// [`Unchanged::Realtime`], since a busy real-time process can freeze the
+    /// whole machine. Use [`set_priority_unchecked`] to set them anyway.
+    ///
+    /// [`set_priority_unchecked`]: Process::set_priority_unchecked
     pub fn set_priority(&mut self, priority: Priority) -> Result<(), Unchanged> {
-        self.0.set_priority(priority.0)
+        if priority.is_realtime() {
+            return Err(Unchanged::Realtime);
+        }
+        self.set_priority_unchecked(priority)
+    }
+    /// Update the priority of this process, even if it's
+    /// [real-time](Priority::is_realtime)
+    ///
+    /// Make sure a real-time process regularly waits for something, using OS
+    /// APIs like [`std::thread::sleep`], or it'll stop everything else from
+    /// running.
+    ///
+    /// With the `tracing` feature, every attempt is logged as a `DEBUG` event.
+    pub fn set_priority_unchecked(&mut self, priority: Priority) -> Result<(), Unchanged> {
+        let result = self.0.set_priority(priority.0);
+        #[cfg(feature = "tracing")]
+        tracing::debug!(pid = self.id(), ?priority, ?result, "set process priority");
+        #[cfg(feature = "std")]
+        {
+            self.0.cached = None;
+        }
+        result
+    }
+    /// Update the priority of this process, returning the priority it had
+    /// before.
+    ///
+    /// This isn't atomic: the OS doesn't offer a way to swap priorities, so
+    /// the old priority is read first and the new one is set afterwards. If
+    /// something else changes the priority in between, that change is lost.
+    ///
+    /// ```rust
+    /// # use scrummage::{Process, Priority};
+    /// let mut me = Process::current();
+    /// let boring = Priority::normal().lower().next().unwrap();
+    /// assert_eq!(me.swap_priority(boring).unwrap(), Priority::normal());
+    /// assert_eq!(me.priority().unwrap(), boring);
+    /// ```
+    pub fn swap_priority(&mut self, priority: Priority) -> Result<Priority, Unchanged> {
+        let old = self.priority()?;
+        self.set_priority(priority)?;
+        Ok(old)
+    }
+    /// Update the priority of this process, unless it already has it,
+    /// returning whether it was changed.
+    ///
+    /// This costs an extra syscall to read the current priority, but skips
+    /// setting it when nothing would change. That's worth it when the same
+    /// priority is applied over and over, like on every config reload.
+    ///
+    /// ```rust
+    /// # use scrummage::{Process, Priority};
+    /// let mut me = Process::current();
+    /// let boring = Priority::normal().lower().next().unwrap();
+    /// assert!(me.set_priority_if_changed(boring).unwrap());
+    /// assert!(!me.set_priority_if_changed(boring).unwrap());
+    /// ```
+    pub fn set_priority_if_changed(&mut self, priority: Priority) -> Result<bool, Unchanged> {
+        if self.priority()? == priority {
+            return Ok(false);
+        }
+        self.set_priority(priority)?;
+        Ok(true)
+    }
+    /// Check whether [`set_priority`](Process::set_priority) would succeed,
+    /// without leaving the priority changed
+    ///
+    /// This is best-effort on Unix. Raising a priority is checked by raising
+    /// it and then lowering it back, which is always allowed. Lowering it is
+    /// checked by setting the priority the process already has, since
+    /// lowering it for real couldn't be undone without privileges. Either
+    /// way, a change made by something else at the same moment could be
+    /// overwritten.
+    ///
+    /// ```rust
+    /// # use scrummage::{Process, Priority};
+    /// let me = Process::current();
+    /// assert!(me.check_set_priority(Priority::lowest()).is_ok());
+    /// assert_eq!(me.priority().unwrap(), Priority::normal());
+    /// assert!(Process::from_pid(u32::MAX).check_set_priority(Priority::lowest()).is_err());
+    /// ```
+    pub fn check_set_priority(&self, priority: Priority) -> Result<(), Unchanged> {
+        if priority.is_realtime() {
+            return Err(Unchanged::Realtime);
+        }
+        let mut probe = Process::from_pid(self.id());
+        let current = probe.priority()?;
+        if priority > current {
+            probe.with_priority(priority)?.restore_now()
+        } else {
+            probe.set_priority(current)
+        }
+    }
+    /// Update the priority of this process, but only if it's still
+    /// `expected`, returning whether it was changed.
+    ///
+    /// This stops one controller from overwriting a priority another has
+    /// just set. It isn't atomic, since no OS offers a way to do that: the
+    /// priority is read and then written, and a change in between will be
+    /// lost. It does close the much wider gap between a caller reading the
+    /// priority and deciding what to do with it.
+    ///
+    /// ```rust
+    /// # use scrummage::{Process, Priority};
+    /// let mut me = Process::current();
+    /// let boring = Priority::normal().lower().next().unwrap();
+    /// assert!(!me.compare_and_set_priority(boring, Priority::lowest()).unwrap());
+    /// assert!(me.compare_and_set_priority(Priority::normal(), boring).unwrap());
+    /// assert_eq!(me.priority().unwrap(), boring);
+    /// ```
+    pub fn compare_and_set_priority(
+        &mut self,
+        expected: Priority,
+        new: Priority,
+    ) -> Result<bool, Unchanged> {
+        if self.priority()? != expected {
+            return Ok(false);
+        }
+        self.set_priority(new)?;
+        Ok(true)
+    }
+    /// Set the priority that `policy` asks for right now
+    ///
+    /// Relative policies are [resolved](PriorityPolicy::resolve) each time,
+    /// so this can be called again to follow changes to our own priority.
+    pub fn apply_policy(&mut self, policy: PriorityPolicy) -> Result<(), Unchanged> {
+        self.set_priority(policy.resolve())
     }
     /// Fetch the priority of this process
     pub fn priority(&self) -> Result<Priority, NotFound> {
         self.0.priority().map(Priority)
     }
+    /// Compare the priority of this process with another's
+    ///
+    /// This is `Greater` if this process has the higher priority. Either
+    /// process being gone gives [`NotFound`].
+    ///
+    /// ```rust
+    /// # use scrummage::{Process, Priority};
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut hog = std::process::Command::new("sleep").arg("1").spawn().unwrap();
+    /// let mut process = Process::of_child(&mut hog);
+    /// process.set_priority(Priority::lowest()).unwrap();
+    /// assert_eq!(Process::current().priority_cmp(&process).unwrap(), Ordering::Greater);
+    /// # hog.kill().unwrap();
+    /// # hog.wait().unwrap();
+    /// ```
+    pub fn priority_cmp(&self, other: &Process<'_>) -> Result<core::cmp::Ordering, NotFound> {
+        Ok(self.priority()?.cmp(&other.priority()?))
+    }
+    /// The priority the OS scheduler actually uses for this process, as a
+    /// raw number
+    ///
+    /// This is useful to compare with what tools like `top` and `ps` show.
+    /// On Unix it's the `PRI` column of `top`: `20 + niceness` for normal
+    /// processes. On Linux, this is read from `/proc`, so real-time processes
+    /// give `-1 - sched_priority`. The values mean different things on each
+    /// platform, so prefer [`priority`](Process::priority) for anything else.
+    ///
+    /// ```rust
+    /// # use scrummage::Process;
+    /// # #[cfg(unix)]
+    /// assert_eq!(Process::current().base_priority().unwrap(), 20);
+    /// ```
+    pub fn base_priority(&self) -> Result<i32, NotFound> {
+        self.0.base_priority()
+    }
+    /// Ask this process to exit
+    ///
+    /// This sends `SIGTERM` on Unix, which the process can handle to clean up
+    /// before exiting, or ignore entirely. Use [`kill`](Process::kill) to
+    /// stop it unconditionally.
+    /// A process that has already exited and been waited on gives
+    /// [`NotSignalled::NotFound`].
+    ///
+    /// ```rust
+    /// # use scrummage::Process;
+    /// let mut child = std::process::Command::new("sleep").arg("10").spawn().unwrap();
+    /// Process::of_child(&mut child).terminate().unwrap();
+    /// assert!(!child.wait().unwrap().success());
+    /// ```
+    pub fn terminate(&mut self) -> Result<(), NotSignalled> {
+        self.0.terminate()
+    }
+    /// Stop this process straight away
+    ///
+    /// This sends `SIGKILL` on Unix, which the process can't catch.
+    pub fn kill(&mut self) -> Result<(), NotSignalled> {
+        self.0.kill()
+    }
+    /// Make this process run in the background, as unobtrusively as the OS
+    /// allows
+    ///
+    /// This gives it the [lowest](Priority::lowest) priority. On Linux, it
+    /// also moves the process to the idle I/O class, so it only reads and
+    /// writes the disk when nothing else is.
+    ///
+    /// ```rust
+    /// # use scrummage::{Process, Priority};
+    /// let mut indexer = std::process::Command::new("sleep").arg("1").spawn().unwrap();
+    /// let mut process = Process::of_child(&mut indexer);
+    /// process.set_background().unwrap();
+    /// assert_eq!(process.priority().unwrap(), Priority::lowest());
+    /// # indexer.kill().unwrap();
+    /// # indexer.wait().unwrap();
+    /// ```
+    pub fn set_background(&mut self) -> Result<(), Unchanged> {
+        self.set_priority(Priority::lowest())?;
+        #[cfg(target_os = "linux")]
+        self.0.set_io_priority(os::linux::IoPriority::Idle)?;
+        Ok(())
+    }
+    /// Whether this process is running in the background, as set up by
+    /// [`set_background`](Process::set_background)
+    ///
+    /// This checks each part of the background configuration: the
+    /// [lowest](Priority::lowest) priority and, on Linux, the idle I/O class.
+    ///
+    /// ```rust
+    /// # use scrummage::Process;
+    /// let mut child = std::process::Command::new("sleep").arg("1").spawn().unwrap();
+    /// let mut process = Process::of_child(&mut child);
+    /// assert!(!process.is_background().unwrap());
+    /// process.set_background().unwrap();
+    /// assert!(process.is_background().unwrap());
+    /// # child.kill().unwrap();
+    /// # child.wait().unwrap();
+    /// ```
+    pub fn is_background(&self) -> Result<bool, NotFound> {
+        let background = self.priority()? == Priority::lowest();
+        #[cfg(target_os = "linux")]
+        let background = background && self.0.io_priority()? == os::linux::IoPriority::Idle;
+        Ok(background)
+    }
+    /// Undo [`set_background`](Process::set_background), giving this process
+    /// the [normal](Priority::normal) priority again
+    ///
+    /// On Unix, raising the priority back up needs privileges, even if the
+    /// process lowered it itself.
+    pub fn set_foreground(&mut self) -> Result<(), Unchanged> {
+        self.set_priority(Priority::normal())?;
+        #[cfg(target_os = "linux")]
+        self.0.reset_io_priority()?;
+        Ok(())
+    }
+}
+
+impl<'p> Process<'p> {
+    /// Temporarily update the priority of this process
+    ///
+    /// The priority is restored when the returned guard is dropped. Use
+    /// [`PriorityGuard::restore_now`] to find out whether restoring it worked:
+    /// on Unix, raising a priority back up usually needs privileges, even if
+    /// it was lowered by the same process.
+    ///
+    /// ```rust,no_run
+    /// # use scrummage::{Process, Priority};
+    /// let mut me = Process::current();
+    /// let boring = Priority::normal().lower().next().unwrap();
+    /// {
+    ///     let _guard = me.with_priority(boring).unwrap();
+    ///     // Do some unimportant work
+    /// }
+    /// assert_eq!(me.priority().unwrap(), Priority::normal());
+    /// ```
+    pub fn with_priority(
+        &mut self,
+        priority: Priority,
+    ) -> Result<PriorityGuard<'_, 'p>, Unchanged> {
+        let previous = self.swap_priority(priority)?;
+        Ok(PriorityGuard {
+            process: self,
+            previous,
+        })
+    }
+}
+
+/// Restores the priority of a [`Process`] when dropped.
+///
+/// Created by [`Process::with_priority`]. Any error while restoring the
+/// priority on drop is ignored.
+#[derive(Debug)]
+#[must_use = "the priority is restored as soon as the guard is dropped"]
+pub struct PriorityGuard<'a, 'p> {
+    process: &'a mut Process<'p>,
+    previous: Priority,
+}
+
+impl PriorityGuard<'_, '_> {
+    /// The priority that will be restored
+    pub fn previous(&self) -> Priority {
+        self.previous
+    }
+    /// Restore the priority straight away, reporting whether it worked.
+    pub fn restore_now(self) -> Result<(), Unchanged> {
+        let mut guard = core::mem::ManuallyDrop::new(self);
+        let previous = guard.previous;
+        guard.process.set_priority(previous)
+    }
+}
+
+impl Drop for PriorityGuard<'_, '_> {
+    fn drop(&mut self) {
+        let _ = self.process.set_priority(self.previous);
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Process<'_> {
+    /// Restrict this process to running on the given set of cores
+    ///
+    /// This is useful to stop noisy background work from competing with
+    /// everything else on the machine.
+    ///
+    /// ```rust
+    /// # use scrummage::{CpuSet, Process};
+    /// let mut me = Process::current();
+    /// let first = me.affinity().unwrap().iter().next().unwrap();
+    /// me.set_affinity(&Some(first).into_iter().collect()).unwrap();
+    /// assert_eq!(me.affinity().unwrap().len(), 1);
+    /// ```
+    ///
+    /// If `set` doesn't contain any of the cores the process is permitted to
+    /// run on, this fails with an [`Unchanged::Os`] error.
+    pub fn set_affinity(&mut self, set: &CpuSet) -> Result<(), Unchanged> {
+        self.0.set_affinity(set)
+    }
+    /// Fetch the set of cores this process can run on
+    pub fn affinity(&self) -> Result<CpuSet, NotFound> {
+        self.0.affinity()
+    }
+}
+
+#[cfg(all(target_os = "linux", feature = "std"))]
+impl Process<'_> {
+    /// Find every running process with the given name
+    ///
+    /// On Linux this is the command name in `/proc/<pid>/comm`, which is
+    /// usually the file name of the program, and is compared case-sensitively.
+    /// The kernel cuts command names down to 15 bytes, so a longer `name`
+    /// matches any process whose name is the start of it.
+    ///
+    /// ```rust
+    /// # use scrummage::Process;
+    /// let mut child = std::process::Command::new("sleep").arg("1").spawn().unwrap();
+    /// let sleepers = Process::find_by_name("sleep");
+    /// assert!(sleepers.iter().any(|p| p.id() == child.id()));
+    /// # child.kill().unwrap();
+    /// # child.wait().unwrap();
+    /// ```
+    pub fn find_by_name(name: &str) -> Vec<Process<'static>> {
+        imp::Process::find_by_name(name)
+            .into_iter()
+            .map(Process)
+            .collect()
+    }
+    /// Find the process that started this one
+    ///
+    /// If the parent has already exited, the process will have been adopted,
+    /// usually by `init`, and that's what this will return instead.
+    ///
+    /// ```rust
+    /// # use scrummage::Process;
+    /// let mut child = std::process::Command::new("sleep").arg("1").spawn().unwrap();
+    /// let parent = Process::of_child(&mut child).parent().unwrap();
+    /// assert_eq!(parent.id(), std::process::id());
+    /// # child.kill().unwrap();
+    /// # child.wait().unwrap();
+    /// ```
+    pub fn parent(&self) -> Result<Process<'static>, NotFound> {
+        self.0.parent().map(Process)
+    }
+    /// Find the processes this one has started
+    ///
+    /// Children that have exited, but haven't been waited on yet, are
+    /// included.
+    ///
+    /// ```rust
+    /// # use scrummage::Process;
+    /// let mut child = std::process::Command::new("sleep").arg("1").spawn().unwrap();
+    /// let children = Process::current().children().unwrap();
+    /// assert!(children.iter().any(|c| c.id() == child.id()));
+    /// # child.kill().unwrap();
+    /// # child.wait().unwrap();
+    /// ```
+    pub fn children(&self) -> Result<Vec<Process<'static>>, NotFound> {
+        self.0
+            .children()
+            .map(|children| children.into_iter().map(Process).collect())
+    }
+    /// Find every process started by this one, or by one of its descendants
+    ///
+    /// This is useful to change the priority of a whole tree of processes.
+    /// Processes that start or exit while the tree is being searched may or
+    /// may not be included.
+    pub fn descendants(&self) -> Result<Vec<Process<'static>>, NotFound> {
+        self.0
+            .descendants()
+            .map(|descendants| descendants.into_iter().map(Process).collect())
+    }
+    /// Restrict this process to the cores of a NUMA node
+    ///
+    /// On machines with several sockets, each has its own memory, and it's
+    /// faster for a process to stay near the memory it uses. The node's
+    /// cores are read from `/sys/devices/system/node`, so a node that doesn't
+    /// exist gives an [`Unchanged::Os`] error.
+    ///
+    /// ```rust,no_run
+    /// # use scrummage::Process;
+    /// Process::current().set_numa_node(0).unwrap();
+    /// ```
+    pub fn set_numa_node(&mut self, node: u16) -> Result<(), Unchanged> {
+        self.0.set_numa_node(node)
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+#[derive(Debug)]
+/// A thread running in this process.
+///
+/// Threads can be given their own priority, which is useful when one thread
+/// is particularly latency-sensitive, like an audio renderer. A `Thread` can
+/// only be used by the thread it refers to, since the OS is free to reuse
+/// its ID once it exits.
+///
+/// On Linux, niceness is actually tracked per thread, and
+/// [`Process::set_priority`] only changes the priority of the process's main
+/// thread. New threads inherit the priority of the thread that spawned them.
+/// POSIX says niceness belongs to the whole process, so don't expect this to
+/// carry over to other Unixes.
+///
+/// On macOS, threads are prioritised with a quality of service class
+/// instead; see `os::macos::ThreadExt`.
+pub struct Thread(imp::Thread);
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+impl Thread {
+    /// Get the currently running thread
+    pub fn current() -> Thread {
+        Thread(imp::Thread::current())
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Thread {
+    /// The OS-specific ID of this thread
+    ///
+    /// On Linux this is the thread's `tid`, which is the same as the process
+    /// ID for the main thread.
+    ///
+    /// ```rust
+    /// # use scrummage::{Thread, Process};
+    /// assert_eq!(Thread::current().id(), Process::current().id());
+    /// ```
+    pub fn id(&self) -> u32 {
+        self.0.id()
+    }
+    /// Update the priority of this thread
+    ///
+    /// ```rust
+    /// # use scrummage::{Thread, Priority};
+    /// std::thread::spawn(|| {
+    ///     let mut me = Thread::current();
+    ///     let boring = Priority::normal().lower().next().unwrap();
+    ///     me.set_priority(boring).unwrap();
+    ///     assert_eq!(me.priority().unwrap(), boring);
+    /// }).join().unwrap();
+    /// ```
+    ///
+    /// Like [`Process::set_priority`], this refuses
+    /// [real-time](Priority::is_realtime) priorities.
+    pub fn set_priority(&mut self, priority: Priority) -> Result<(), Unchanged> {
+        if priority.is_realtime() {
+            return Err(Unchanged::Realtime);
+        }
+        self.set_priority_unchecked(priority)
+    }
+    /// Update the priority of this thread, even if it's
+    /// [real-time](Priority::is_realtime)
+    pub fn set_priority_unchecked(&mut self, priority: Priority) -> Result<(), Unchanged> {
+        let result = self.0.set_priority(priority.0);
+        #[cfg(feature = "tracing")]
+        tracing::debug!(tid = self.id(), ?priority, ?result, "set thread priority");
+        result
+    }
+    /// Fetch the priority of this thread
+    pub fn priority(&self) -> Result<Priority, NotFound> {
+        self.0.priority().map(Priority)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Process<'_> {
+    /// Refer to a child process spawned with [`std::process::Command`]
+    ///
+    /// This is the same as `Process::from(child)`, but reads better where
+    /// it's used.
+    ///
+    /// ```rust
+    /// # use scrummage::{Process, Priority};
+    /// let mut child = std::process::Command::new("sleep").arg("1").spawn().unwrap();
+    /// let boring = Priority::normal().lower().next().unwrap();
+    /// Process::of_child(&mut child).set_priority(boring).unwrap();
+    /// # child.kill().unwrap();
+    /// # child.wait().unwrap();
+    /// ```
+    pub fn of_child(child: &mut std::process::Child) -> Process<'_> {
+        Process::from(child)
+    }
+    /// Run `child` at `priority` while doing some `work`, then wait for it
+    ///
+    /// The child keeps `priority` until it exits. If `work` panics, though,
+    /// this tries to put back the child's previous priority before the
+    /// panic carries on. Like [`with_priority`](Process::with_priority),
+    /// that's only best-effort: on Unix, raising a priority back up usually
+    /// needs privileges, so a lowered child normally stays lowered.
+    ///
+    /// Failing to update the priority is the outer error, reported before
+    /// `work` runs and leaving the child to be waited on by the caller. The
+    /// inner result is from waiting for the child.
+    ///
+    /// ```rust
+    /// # use scrummage::{Process, Priority};
+    /// fn run_boring(mut child: std::process::Child) -> Result<bool, Box<dyn std::error::Error>> {
+    ///     let boring = Priority::normal().lower().next().unwrap();
+    ///     let status = Process::wait_at_priority(&mut child, boring, || {
+    ///         // Do some other work while the child runs
+    ///     })??;
+    ///     Ok(status.success())
+    /// }
+    /// let child = std::process::Command::new("true").spawn().unwrap();
+    /// assert!(run_boring(child).unwrap());
+    /// ```
+    pub fn wait_at_priority(
+        child: &mut std::process::Child,
+        priority: Priority,
+        work: impl FnOnce(),
+    ) -> Result<std::io::Result<std::process::ExitStatus>, Unchanged> {
+        {
+            let mut process = Process::of_child(child);
+            let guard = process.with_priority(priority)?;
+            work();
+            // Only try to restore the priority on a panic: the child should
+            // keep `priority` until it exits
+            core::mem::forget(guard);
+        }
+        Ok(child.wait())
+    }
+}
+
+#[cfg(feature = "std")]
+impl Process<'_> {
+    /// Fetch the priority of this process, reusing the last value read if
+    /// it's no older than `max_age`
+    ///
+    /// This saves a syscall for monitors that poll many processes, at the
+    /// cost of missing changes for up to `max_age`. Setting the priority
+    /// through this `Process` forgets the cached value, but changes made by
+    /// anything else, including other `Process` values for the same process,
+    /// aren't noticed until it expires.
+    ///
+    /// ```rust
+    /// # use scrummage::{Process, Priority};
+    /// use std::time::Duration;
+    ///
+    /// let mut me = Process::current();
+    /// let second = Duration::from_secs(1);
+    /// assert_eq!(me.priority_cached(second).unwrap(), Priority::normal());
+    /// Process::current().set_priority(Priority::lowest()).unwrap();
+    /// // Still cached
+    /// assert_eq!(me.priority_cached(second).unwrap(), Priority::normal());
+    /// std::thread::sleep(Duration::from_millis(10));
+    /// let fresh = Duration::from_millis(5);
+    /// assert_eq!(me.priority_cached(fresh).unwrap(), Priority::lowest());
+    /// ```
+    pub fn priority_cached(&mut self, max_age: std::time::Duration) -> Result<Priority, NotFound> {
+        if let Some((priority, read)) = self.0.cached {
+            if read.elapsed() <= max_age {
+                return Ok(Priority(priority));
+            }
+        }
+        let priority = self.priority()?;
+        self.0.cached = Some((priority.0, std::time::Instant::now()));
+        Ok(priority)
+    }
+    /// Update the priority of this process, reporting failure as an
+    /// [`io::Error`](std::io::Error)
+    ///
+    /// This is [`set_priority`](Process::set_priority) for callers that would
+    /// rather not handle [`Unchanged`] themselves.
+    ///
+    /// ```rust
+    /// # use scrummage::{Process, Priority};
+    /// fn be_boring() -> std::io::Result<()> {
+    ///     Process::current().set_priority_io(Priority::lowest())?;
+    ///     Ok(())
+    /// }
+    /// be_boring().unwrap();
+    /// ```
+    pub fn set_priority_io(&mut self, priority: Priority) -> std::io::Result<()> {
+        Ok(self.set_priority(priority)?)
+    }
+    /// Fetch the priority of this process, reporting failure as an
+    /// [`io::Error`](std::io::Error)
+    pub fn priority_io(&self) -> std::io::Result<Priority> {
+        Ok(self.priority()?)
+    }
+    /// Every priority this process could be given right now, from the
+    /// [lowest](Priority::lowest) up
+    ///
+    /// Without privileges, Unix only lets priorities be lowered, so this
+    /// stops at the current priority. On Linux, `CAP_SYS_NICE` allows every
+    /// level, and the process's `RLIMIT_NICE` can allow some above the
+    /// current one. A process belonging to another user can't be changed
+    /// without privileges at all, so it has no reachable priorities.
+    ///
+    /// ```rust
+    /// # use scrummage::{Process, Priority};
+    /// let reachable = Process::current().reachable_priorities().unwrap();
+    /// assert_eq!(reachable.first(), Some(&Priority::lowest()));
+    /// assert!(reachable.contains(&Process::current().priority().unwrap()));
+    /// ```
+    pub fn reachable_priorities(&self) -> Result<Vec<Priority>, NotFound> {
+        Ok(match self.0.highest_reachable()? {
+            Some(highest) => Priority::all().take_while(|&p| p.0 <= highest).collect(),
+            None => vec![],
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl Process<'_> {
+    /// Refer to a child process through a shared reference
+    ///
+    /// Unlike [`of_child`](Process::of_child), this leaves the [`Child`] free
+    /// for other uses that only need a shared reference, while still
+    /// preventing it from being waited on (which would free up its pid for
+    /// reuse).
+    ///
+    /// [`Child`]: std::process::Child
+    ///
+    /// ```rust
+    /// # use scrummage::{Process, Priority};
+    /// let mut child = std::process::Command::new("sleep").arg("1").spawn().unwrap();
+    /// let process = Process::from_child_ref(&child);
+    /// println!("{} is at {}", child.id(), process.priority().unwrap());
+    /// # child.kill().unwrap();
+    /// # child.wait().unwrap();
+    /// ```
+    pub fn from_child_ref(child: &std::process::Child) -> Process<'_> {
+        Process::from(child)
+    }
+}
+
+/// Refers to a child process without needing exclusive access to it.
+///
+/// ```rust
+/// # use scrummage::{Process, Priority};
+/// let mut child = std::process::Command::new("sleep").arg("1").spawn().unwrap();
+/// let mut process = Process::from(&child);
+/// process.set_priority(Priority::lowest()).unwrap();
+/// println!("lowered {}", child.id());
+/// # child.kill().unwrap();
+/// # child.wait().unwrap();
+/// ```
+#[cfg(feature = "std")]
+impl<'a> From<&'a std::process::Child> for Process<'a> {
+    fn from(child: &'a std::process::Child) -> Self {
+        Self(child.into())
+    }
+}
+
+/// Refers to the process with this pid, like [`Process::from_pid`].
+///
+/// This is the pid type of `std` and of crates like `sysinfo`, through
+/// `Pid::as_u32`.
+///
+/// ```rust
+/// # use scrummage::Process;
+/// let me = Process::from(std::process::id());
+/// assert_eq!(me.id(), std::process::id());
+/// ```
+impl From<u32> for Process<'_> {
+    fn from(pid: u32) -> Self {
+        Process(imp::Process::from_pid(pid))
+    }
+}
+
+/// Refers to the process with this pid, as used by `libc` and crates like
+/// `procfs`.
+///
+/// Negative pids never refer to a single process, so they're
+/// [`NotFound`].
+///
+/// ```rust
+/// # use scrummage::Process;
+/// use std::convert::TryFrom;
+///
+/// let me = Process::try_from(std::process::id() as i32).unwrap();
+/// assert_eq!(me.id(), std::process::id());
+/// assert!(Process::try_from(-1).is_err());
+/// ```
+impl core::convert::TryFrom<i32> for Process<'_> {
+    type Error = NotFound;
+    fn try_from(pid: i32) -> Result<Self, NotFound> {
+        let pid = u32::try_from(pid).map_err(|_| NotFound)?;
+        Ok(Process::from(pid))
+    }
+}
+
+/// Processes are equal when they refer to the same OS process, which is
+/// decided by their [`id`](Process::id).
+///
+/// ```rust
+/// # use scrummage::Process;
+/// assert_eq!(Process::current(), Process::from_pid(std::process::id()));
+/// ```
+impl PartialEq for Process<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for Process<'_> {}
+
+/// Hashes the [`id`](Process::id), consistently with equality, so processes
+/// can be used as map keys.
+///
+/// ```rust
+/// # use scrummage::{Priority, Process};
+/// use std::collections::HashMap;
+///
+/// let mut policies = HashMap::new();
+/// policies.insert(Process::current(), Priority::lowest());
+/// assert_eq!(policies[&Process::from_pid(std::process::id())], Priority::lowest());
+/// ```
+impl core::hash::Hash for Process<'_> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.id().hash(state)
+    }
 }
 
-// TODO: This API sorta sucks. Would Process::of_child(&Child) be better?
-// The name's less than obvious
 #[cfg(feature = "std")]
 impl<'a> From<&'a mut std::process::Child> for Process<'a> {
     fn from(child: &'a mut std::process::Child) -> Self {
-        Self(child.into())
+        Self((&*child).into())
     }
 }
 
 /// The process couldn't be found.
 ///
-/// See [`Process`] for details.
+/// See [`Process`] for details. Once a process has exited and been waited
+/// on, it's gone:
+///
+/// ```rust
+/// # use scrummage::{NotFound, Process};
+/// let mut child = std::process::Command::new("true").spawn().unwrap();
+/// let id = child.id();
+/// child.wait().unwrap();
+///
+/// match Process::from_pid(id).priority() {
+///     Ok(priority) => println!("{} was reused, and has {}", id, priority),
+///     Err(NotFound) => println!("{} has exited", id),
+/// }
+/// ```
 #[derive(Debug)]
 pub struct NotFound;
 
+/// A priority step was beyond the levels available on this platform.
+///
+/// Returned by [`Priority`]'s `TryFrom<i32>` implementation.
+#[derive(Debug)]
+pub struct OutOfRange;
+
 /// The reason the priority of a process couldn't be set.
+///
+/// More reasons may be added as new platforms and features are supported,
+/// so matches on this need a catch-all arm.
+///
+/// ```rust
+/// # use scrummage::{Priority, Process, Unchanged};
+/// let mut me = Process::current();
+/// // Raising a priority needs privileges on most platforms
+/// match me.set_priority(Priority::highest()) {
+///     Ok(()) => println!("running with privileges"),
+///     Err(Unchanged::PermissionDenied) | Err(Unchanged::NiceLimit(_)) => {
+///         println!("staying at {}", me.priority().unwrap())
+///     }
+///     Err(Unchanged::NotFound(_)) => unreachable!("we're still running"),
+///     Err(e) => panic!("couldn't raise our priority: {}", e),
+/// }
+/// ```
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Unchanged {
     // This could be much cleaner with [enum variant types], which would
     // let `Process::priority` return `Result<Priority, Error::NotFound>`
@@ -131,6 +1408,49 @@ pub enum Unchanged {
     /// you are setting up the right permissions. If the details of this error
     /// would be useful for you, do file an issue about your use case! 😁 
     PermissionDenied,
+    /// The priority was above what the `RLIMIT_NICE` resource limit allows
+    /// without the `CAP_SYS_NICE` capability. Only returned on Linux.
+    ///
+    /// This holds the highest priority the target process's limit allowed
+    /// when the error happened. The default limit doesn't allow raising a
+    /// priority at all, which includes putting back a priority the process
+    /// had before lowering it, so this holds the [lowest](Priority::lowest)
+    /// priority.
+    ///
+    /// Process groups and users, which can mix limits, report
+    /// [`PermissionDenied`](Unchanged::PermissionDenied) instead, as does a
+    /// limit that couldn't be read.
+    NiceLimit(Priority),
+    /// The OS reported an error that scrummage doesn't recognise.
+    ///
+    /// This holds the raw error code, which is an `errno` value on Unix.
+    Os(i32),
+    /// The priority was real-time, and the method used refuses those.
+    ///
+    /// See [`Process::set_priority_unchecked`].
+    Realtime,
+    /// This platform doesn't support changing priorities.
+    ///
+    /// Targets that scrummage doesn't have a backend for, like
+    /// `wasm32-unknown-unknown`, still build, but report every process as
+    /// having the [normal](Priority::normal) priority and refuse to change it.
+    Unsupported,
+}
+
+impl Unchanged {
+    /// The raw OS error code, if the error wasn't one scrummage recognises.
+    ///
+    /// ```rust
+    /// # use scrummage::Unchanged;
+    /// assert_eq!(Unchanged::Os(22).raw_os_error(), Some(22));
+    /// assert_eq!(Unchanged::PermissionDenied.raw_os_error(), None);
+    /// ```
+    pub fn raw_os_error(&self) -> Option<i32> {
+        match self {
+            Self::Os(code) => Some(*code),
+            _ => None,
+        }
+    }
 }
 
 impl From<NotFound> for Unchanged {
@@ -139,6 +1459,160 @@ impl From<NotFound> for Unchanged {
     }
 }
 
+/// The reason a process couldn't be sent a signal, by
+/// [`Process::terminate`] or [`Process::kill`].
+///
+/// ```rust
+/// # use scrummage::{NotSignalled, Process};
+/// let mut child = std::process::Command::new("true").spawn().unwrap();
+/// let id = child.id();
+/// child.wait().unwrap();
+/// match Process::from_pid(id).terminate() {
+///     Err(NotSignalled::NotFound(_)) => println!("{} already exited", id),
+///     result => panic!("signalled a waited-on process: {:?}", result),
+/// }
+/// ```
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum NotSignalled {
+    /// The process doesn't exist.
+    NotFound(NotFound),
+    /// The [`Process`] handle didn't have the suitable permissions to send
+    /// the signal, usually because it belongs to another user.
+    PermissionDenied,
+    /// The OS reported an error that scrummage doesn't recognise.
+    ///
+    /// This holds the raw error code, which is an `errno` value on Unix.
+    Os(i32),
+    /// This platform doesn't support sending signals.
+    Unsupported,
+}
+
+impl From<NotFound> for NotSignalled {
+    fn from(n: NotFound) -> Self {
+        Self::NotFound(n)
+    }
+}
+
+/// Find the priority `step` levels below [normal](Priority::normal), or above
+/// it if `step` is negative, like the increments of the `nice` utility.
+///
+/// Steps beyond the [lowest](Priority::lowest) or
+/// [highest](Priority::highest) priority are an error rather than being
+/// clamped.
+///
+/// ```rust
+/// # use scrummage::Priority;
+/// use std::convert::TryFrom;
+///
+/// let below = Priority::normal().lower().len() as i32;
+/// let above = Priority::normal().higher().len() as i32;
+/// assert_eq!(Priority::try_from(0).unwrap(), Priority::normal());
+/// assert_eq!(Priority::try_from(below).unwrap(), Priority::lowest());
+/// assert_eq!(Priority::try_from(-above).unwrap(), Priority::highest());
+/// assert!(Priority::try_from(below + 1).is_err());
+/// assert!(Priority::try_from(-above - 1).is_err());
+/// ```
+impl core::convert::TryFrom<i32> for Priority {
+    type Error = OutOfRange;
+    fn try_from(step: i32) -> Result<Self, OutOfRange> {
+        let steps = step.unsigned_abs() as usize;
+        let level = if step > 0 {
+            Self::normal().lower().nth(steps - 1)
+        } else if step < 0 {
+            Self::normal().higher().nth(steps - 1)
+        } else {
+            Some(Self::normal())
+        };
+        level.ok_or(OutOfRange)
+    }
+}
+
+/// Moves the priority `steps` levels *lower*, like raising the niceness, or
+/// higher if `steps` is negative.
+///
+/// This saturates at the [lowest](Priority::lowest) and
+/// [highest](Priority::highest) levels, matching [`Priority::as_step`].
+///
+/// ```rust
+/// # use scrummage::Priority;
+/// let normal = Priority::normal();
+/// assert_eq!(normal + 1, normal.lower().next().unwrap());
+/// assert_eq!(normal + -1, normal.higher().next().unwrap());
+/// assert_eq!(Priority::lowest() + 1, Priority::lowest());
+/// assert_eq!(normal + i32::MAX, Priority::lowest());
+/// assert_eq!(normal + i32::MIN, Priority::highest());
+/// ```
+impl core::ops::Add<i32> for Priority {
+    type Output = Self;
+    fn add(self, steps: i32) -> Self {
+        let levels = steps.unsigned_abs() as usize;
+        if steps >= 0 {
+            self.saturating_lower(levels)
+        } else {
+            self.saturating_higher(levels)
+        }
+    }
+}
+
+/// Moves the priority `steps` levels *higher*, or lower if `steps` is
+/// negative.
+///
+/// This is the opposite of adding, and saturates in the same way.
+///
+/// ```rust
+/// # use scrummage::Priority;
+/// let normal = Priority::normal();
+/// assert_eq!(normal - 1, normal.higher().next().unwrap());
+/// assert_eq!(Priority::highest() - 1, Priority::highest());
+/// assert_eq!(normal - i32::MAX, Priority::highest());
+/// assert_eq!(normal - i32::MIN, Priority::lowest());
+/// assert_eq!(normal + 3 - 3, normal);
+/// ```
+impl core::ops::Sub<i32> for Priority {
+    type Output = Self;
+    fn sub(self, steps: i32) -> Self {
+        let levels = steps.unsigned_abs() as usize;
+        if steps >= 0 {
+            self.saturating_higher(levels)
+        } else {
+            self.saturating_lower(levels)
+        }
+    }
+}
+
+/// Describes the priority in the platform's own terms.
+///
+/// ```rust
+/// # use scrummage::Priority;
+/// # #[cfg(unix)]
+/// assert_eq!(Priority::normal().to_string(), "niceness 0");
+/// ```
+impl core::fmt::Display for Priority {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// Shows the platform's own value for the priority, which is the niceness on
+/// Unix.
+///
+/// ```rust
+/// # use scrummage::Priority;
+/// assert_eq!(format!("{:?}", Priority::normal()), "Priority(nice=0)");
+/// ```
+impl core::fmt::Debug for Priority {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Priority({:?})", self.0)
+    }
+}
+
+impl core::fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("priority step out of range")
+    }
+}
+
 impl core::fmt::Display for NotFound {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         f.write_str("couldn't set priority of missing process")
@@ -150,6 +1624,27 @@ impl core::fmt::Display for Unchanged {
         match self {
             Self::NotFound(n) => core::fmt::Display::fmt(n, f),
             Self::PermissionDenied => f.write_str("missing permissions to set priority"),
+            Self::NiceLimit(ceiling) if *ceiling == Priority::lowest() => {
+                f.write_str("raising priority needs CAP_SYS_NICE or a higher RLIMIT_NICE")
+            }
+            Self::NiceLimit(ceiling) => write!(
+                f,
+                "RLIMIT_NICE only allows raising priority to {} without CAP_SYS_NICE",
+                ceiling
+            ),
+            Self::Realtime => f.write_str("refused to set a real-time priority"),
+            Self::Unsupported => f.write_str("priorities aren't supported on this platform"),
+            Self::Os(code) => {
+                #[cfg(feature = "std")]
+                {
+                    let e = std::io::Error::from_raw_os_error(*code);
+                    write!(f, "couldn't set priority: {}", e)
+                }
+                #[cfg(not(feature = "std"))]
+                {
+                    write!(f, "couldn't set priority: OS error {}", code)
+                }
+            }
         }
     }
 }
@@ -158,4 +1653,91 @@ impl core::fmt::Display for Unchanged {
 impl std::error::Error for NotFound {}
 
 #[cfg(feature = "std")]
-impl std::error::Error for Unchanged {}
+impl std::error::Error for OutOfRange {}
+
+impl core::fmt::Display for NotSignalled {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::NotFound(_) => f.write_str("couldn't signal missing process"),
+            Self::PermissionDenied => f.write_str("missing permissions to signal process"),
+            Self::Unsupported => f.write_str("signals aren't supported on this platform"),
+            Self::Os(code) => {
+                #[cfg(feature = "std")]
+                {
+                    let e = std::io::Error::from_raw_os_error(*code);
+                    write!(f, "couldn't signal process: {}", e)
+                }
+                #[cfg(not(feature = "std"))]
+                {
+                    write!(f, "couldn't signal process: OS error {}", code)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Unchanged {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::NotFound(n) => Some(n),
+            Self::PermissionDenied
+            | Self::NiceLimit(_)
+            | Self::Os(_)
+            | Self::Realtime
+            | Self::Unsupported => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<NotFound> for std::io::Error {
+    fn from(n: NotFound) -> Self {
+        Self::new(std::io::ErrorKind::NotFound, n)
+    }
+}
+
+/// Converts to the matching [`std::io::ErrorKind`], keeping the raw OS error
+/// when there is one.
+///
+/// ```rust
+/// # use scrummage::Unchanged;
+/// let e = std::io::Error::from(Unchanged::PermissionDenied);
+/// assert_eq!(e.kind(), std::io::ErrorKind::PermissionDenied);
+/// ```
+#[cfg(feature = "std")]
+impl From<Unchanged> for std::io::Error {
+    fn from(e: Unchanged) -> Self {
+        match e {
+            Unchanged::NotFound(n) => n.into(),
+            Unchanged::PermissionDenied | Unchanged::NiceLimit(_) => {
+                Self::new(std::io::ErrorKind::PermissionDenied, e)
+            }
+            Unchanged::Os(code) => Self::from_raw_os_error(code),
+            Unchanged::Realtime => Self::other(e),
+            Unchanged::Unsupported => Self::new(std::io::ErrorKind::Unsupported, e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NotSignalled {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::NotFound(n) => Some(n),
+            Self::PermissionDenied | Self::Os(_) | Self::Unsupported => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<NotSignalled> for std::io::Error {
+    fn from(e: NotSignalled) -> Self {
+        match e {
+            NotSignalled::NotFound(_) => Self::new(std::io::ErrorKind::NotFound, e),
+            NotSignalled::PermissionDenied => Self::new(std::io::ErrorKind::PermissionDenied, e),
+            NotSignalled::Os(code) => Self::from_raw_os_error(code),
+            NotSignalled::Unsupported => Self::new(std::io::ErrorKind::Unsupported, e),
+        }
+    }
+}
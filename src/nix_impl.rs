@@ -0,0 +1,35 @@
+//! Conversions for code that already uses `nix` to manage processes.
+//!
+//! `nix` doesn't wrap `getpriority` or `setpriority`, so there's no priority
+//! type to convert to. Its [`Pid`] is how processes are passed around,
+//! though.
+
+use crate::os::unix::ProcessExt;
+use crate::Process;
+use nix::unistd::Pid;
+
+/// Refers to the process with this pid, such as one returned by
+/// [`nix::unistd::fork`].
+///
+/// ```rust
+/// # use scrummage::Process;
+/// let me = Process::from(nix::unistd::Pid::this());
+/// assert_eq!(me.id(), std::process::id());
+/// ```
+impl From<Pid> for Process<'_> {
+    fn from(pid: Pid) -> Self {
+        Process::from_raw_pid(pid.as_raw())
+    }
+}
+
+/// The pid of the process.
+///
+/// ```rust
+/// # use scrummage::Process;
+/// assert_eq!(nix::unistd::Pid::from(&Process::current()), nix::unistd::Pid::this());
+/// ```
+impl From<&Process<'_>> for Pid {
+    fn from(process: &Process<'_>) -> Self {
+        Pid::from_raw(process.id() as _)
+    }
+}
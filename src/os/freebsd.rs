@@ -0,0 +1,68 @@
+//! FreeBSD-specific extensions.
+
+use crate::{NotFound, Process, Unchanged};
+
+/// A FreeBSD real-time priority class, set with `rtprio(2)`.
+///
+/// The levels within the `RealTime` and `Idle` classes range from `0` (the
+/// highest) to `31` (the lowest), and are clamped to that range. Real-time
+/// processes run before every normal one, and idle processes only run when
+/// nothing else wants to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RtPrio {
+    /// `RTP_PRIO_REALTIME`: Always run before normal processes. This needs
+    /// root.
+    RealTime(u8),
+    /// `RTP_PRIO_FIFO`: Like `RealTime`, but isn't preempted by processes
+    /// at the same level. This is what `SCHED_FIFO` processes report, and
+    /// needs root.
+    Fifo(u8),
+    /// `RTP_PRIO_NORMAL`: The default, scheduled by niceness.
+    Normal,
+    /// `RTP_PRIO_IDLE`: Only run when nothing else wants to. This needs root.
+    Idle(u8),
+    /// A class the kernel reported that scrummage doesn't know, holding its
+    /// raw `RTP_PRIO_*` value.
+    ///
+    /// Setting it passes the value on with a level of `0`.
+    Unknown(u16),
+}
+
+/// FreeBSD-specific extensions to [`Process`].
+pub trait ProcessExt: super::private::Sealed {
+    /// Update the real-time priority class of this process
+    ///
+    /// The top real-time and FIFO level, `0`, is refused with
+    /// [`Unchanged::Realtime`], since it can starve the rest of the system. Use
+    /// [`set_rtprio_unchecked`](ProcessExt::set_rtprio_unchecked) to set it
+    /// anyway.
+    ///
+    /// ```rust,no_run
+    /// use scrummage::{os::freebsd::{ProcessExt, RtPrio}, Process};
+    /// let mut me = Process::current();
+    /// me.set_rtprio(RtPrio::RealTime(10)).unwrap();
+    /// assert_eq!(me.rtprio().unwrap(), RtPrio::RealTime(10));
+    /// ```
+    fn set_rtprio(&mut self, rtprio: RtPrio) -> Result<(), Unchanged>;
+    /// Update the real-time priority class of this process, even to the top
+    /// real-time level
+    fn set_rtprio_unchecked(&mut self, rtprio: RtPrio) -> Result<(), Unchanged>;
+    /// Fetch the real-time priority class of this process
+    fn rtprio(&self) -> Result<RtPrio, NotFound>;
+}
+
+impl ProcessExt for Process<'_> {
+    fn set_rtprio(&mut self, rtprio: RtPrio) -> Result<(), Unchanged> {
+        match rtprio {
+            RtPrio::RealTime(0) | RtPrio::Fifo(0) => Err(Unchanged::Realtime),
+            _ => self.set_rtprio_unchecked(rtprio),
+        }
+    }
+    fn set_rtprio_unchecked(&mut self, rtprio: RtPrio) -> Result<(), Unchanged> {
+        self.0.set_rtprio(rtprio)
+    }
+    fn rtprio(&self) -> Result<RtPrio, NotFound> {
+        self.0.rtprio()
+    }
+}
@@ -0,0 +1,156 @@
+//! Linux-specific extensions.
+
+use crate::{NotFound, Process, Unchanged};
+
+/// A Linux scheduling policy.
+///
+/// The policy decides how the kernel picks which thread to run. The normal
+/// policy, [`Other`](SchedPolicy::Other), shares the CPU fairly according to
+/// niceness, while the real-time policies always run before any normal
+/// thread.
+///
+/// See `sched(7)` for the details of each policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SchedPolicy {
+    /// `SCHED_OTHER`: The default time-sharing policy.
+    Other,
+    /// `SCHED_BATCH`: Like `Other`, but for CPU-intensive work that isn't
+    /// interactive.
+    Batch,
+    /// `SCHED_IDLE`: Only runs when there's nothing else to do.
+    Idle,
+    /// `SCHED_FIFO`: Real-time scheduling with the given priority, which is
+    /// clamped to `1..=99`. The thread runs until it blocks or a higher
+    /// priority thread wants to run.
+    Fifo(u8),
+    /// `SCHED_RR`: Like `Fifo`, but threads with the same priority take
+    /// turns.
+    RoundRobin(u8),
+    /// `SCHED_DEADLINE`: Runs for a set time in every period, before any
+    /// other policy.
+    ///
+    /// Its parameters can only be set with `sched_setattr`, so setting this
+    /// policy fails with [`Unchanged::Os`].
+    Deadline,
+    /// A policy the kernel reported that scrummage doesn't know, holding
+    /// its raw `SCHED_*` value.
+    ///
+    /// Setting it passes the value on with a priority of `0`.
+    Unknown(i32),
+}
+
+/// The I/O scheduling class and level of a process.
+///
+/// Like [`Priority`](crate::Priority), this decides which process goes first,
+/// but for disk access instead of CPU time. The levels within a class range
+/// from `0` (the highest) to `7` (the lowest), and are clamped to that range.
+///
+/// The `RealTime` class is privileged: it needs the `CAP_SYS_ADMIN` or
+/// `CAP_SYS_NICE` capability depending on the kernel version. Since Linux
+/// 2.6.25, any process can use the `Idle` class.
+///
+/// See `ioprio_set(2)` for the details of each class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IoPriority {
+    /// Always given access to the disk first.
+    RealTime(u8),
+    /// The default class. Processes that haven't set an I/O priority are
+    /// given a level based on their niceness.
+    BestEffort(u8),
+    /// Only given access to the disk when nothing else needs it.
+    Idle,
+}
+
+/// Linux-specific extensions to [`Process`].
+pub trait ProcessExt: super::private::Sealed {
+    /// Update the scheduling policy of this process
+    ///
+    /// Real-time policies usually need the `CAP_SYS_NICE` capability, and
+    /// return [`Unchanged::PermissionDenied`] without it. The top real-time
+    /// priority, `99`, is refused with [`Unchanged::Realtime`], since it
+    /// can starve even the kernel's own threads. Use
+    /// [`set_sched_policy_unchecked`](ProcessExt::set_sched_policy_unchecked)
+    /// to set it anyway.
+    ///
+    /// ```rust
+    /// use scrummage::{os::linux::{ProcessExt, SchedPolicy}, Process};
+    /// let mut me = Process::current();
+    /// me.set_sched_policy(SchedPolicy::Batch).unwrap();
+    /// assert_eq!(me.sched_policy().unwrap(), SchedPolicy::Batch);
+    /// ```
+    fn set_sched_policy(&mut self, policy: SchedPolicy) -> Result<(), Unchanged>;
+    /// Update the scheduling policy of this process, even to the top
+    /// real-time priority
+    fn set_sched_policy_unchecked(&mut self, policy: SchedPolicy) -> Result<(), Unchanged>;
+    /// Fetch the scheduling policy of this process
+    fn sched_policy(&self) -> Result<SchedPolicy, NotFound>;
+    /// Update the I/O priority of this process, like `ionice`
+    ///
+    /// ```rust
+    /// use scrummage::{os::linux::{IoPriority, ProcessExt}, Process};
+    /// let mut me = Process::current();
+    /// me.set_io_priority(IoPriority::BestEffort(7)).unwrap();
+    /// assert_eq!(me.io_priority().unwrap(), IoPriority::BestEffort(7));
+    /// ```
+    fn set_io_priority(&mut self, priority: IoPriority) -> Result<(), Unchanged>;
+    /// Fetch the I/O priority of this process
+    fn io_priority(&self) -> Result<IoPriority, NotFound>;
+    /// Update the niceness of this process's autogroup
+    ///
+    /// With autogroups enabled, which most desktop distributions do, the
+    /// kernel first shares the CPU fairly between sessions, and only then
+    /// uses niceness to decide between the processes in a session. That
+    /// means renicing a process only affects how it competes with its own
+    /// session. The autogroup's niceness decides how the whole session
+    /// competes with everything else.
+    ///
+    /// The niceness is clamped to `-20..=19`, and is shared by every process
+    /// in the autogroup. A process that doesn't exist, or a kernel without
+    /// autogroups, gives [`Unchanged::NotFound`].
+    ///
+    /// See the "autogroup feature" section of `sched(7)`.
+    ///
+    /// ```rust,no_run
+    /// use scrummage::{os::linux::ProcessExt, Process};
+    /// let mut me = Process::current();
+    /// me.set_autogroup_nice(10).unwrap();
+    /// assert_eq!(me.autogroup_nice().unwrap(), 10);
+    /// ```
+    #[cfg(feature = "std")]
+    fn set_autogroup_nice(&mut self, nice: i32) -> Result<(), Unchanged>;
+    /// Fetch the niceness of this process's autogroup
+    #[cfg(feature = "std")]
+    fn autogroup_nice(&self) -> Result<i32, NotFound>;
+}
+
+impl ProcessExt for Process<'_> {
+    fn set_sched_policy(&mut self, policy: SchedPolicy) -> Result<(), Unchanged> {
+        match policy {
+            SchedPolicy::Fifo(99..=u8::MAX) | SchedPolicy::RoundRobin(99..=u8::MAX) => {
+                Err(Unchanged::Realtime)
+            }
+            _ => self.set_sched_policy_unchecked(policy),
+        }
+    }
+    fn set_sched_policy_unchecked(&mut self, policy: SchedPolicy) -> Result<(), Unchanged> {
+        self.0.set_sched_policy(policy)
+    }
+    fn sched_policy(&self) -> Result<SchedPolicy, NotFound> {
+        self.0.sched_policy()
+    }
+    fn set_io_priority(&mut self, priority: IoPriority) -> Result<(), Unchanged> {
+        self.0.set_io_priority(priority)
+    }
+    fn io_priority(&self) -> Result<IoPriority, NotFound> {
+        self.0.io_priority()
+    }
+    #[cfg(feature = "std")]
+    fn set_autogroup_nice(&mut self, nice: i32) -> Result<(), Unchanged> {
+        self.0.set_autogroup_nice(nice)
+    }
+    #[cfg(feature = "std")]
+    fn autogroup_nice(&self) -> Result<i32, NotFound> {
+        self.0.autogroup_nice()
+    }
+}
@@ -0,0 +1,57 @@
+//! macOS-specific extensions.
+
+use crate::{Thread, Unchanged};
+
+/// A quality of service class for a thread.
+///
+/// macOS mostly ignores niceness when scheduling apps. Instead, each thread
+/// declares what kind of work it's doing, which decides how much CPU time it
+/// gets, whether it runs on the efficiency or performance cores, and how it's
+/// treated by App Nap.
+///
+/// The classes are listed from least to most important.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QosClass {
+    /// `QOS_CLASS_BACKGROUND`: Work the user isn't aware of, like indexing or
+    /// backups.
+    Background,
+    /// `QOS_CLASS_UTILITY`: Long-running work the user is aware of, such as
+    /// a download with a progress bar.
+    Utility,
+    /// `QOS_CLASS_DEFAULT`: The class used when nothing else is known.
+    Default,
+    /// `QOS_CLASS_USER_INITIATED`: Work the user started and is waiting for,
+    /// like opening a document.
+    UserInitiated,
+    /// `QOS_CLASS_USER_INTERACTIVE`: Work that has to finish straight away to
+    /// keep the UI responsive, such as animations.
+    UserInteractive,
+}
+
+/// macOS-specific extensions to [`Thread`].
+pub trait ThreadExt: super::private::Sealed {
+    /// Update the quality of service class of this thread
+    ///
+    /// ```rust
+    /// use scrummage::{os::macos::{QosClass, ThreadExt}, Thread};
+    /// std::thread::spawn(|| {
+    ///     let mut me = Thread::current();
+    ///     me.set_qos(QosClass::Utility).unwrap();
+    ///     assert_eq!(me.qos(), Some(QosClass::Utility));
+    /// }).join().unwrap();
+    /// ```
+    fn set_qos(&mut self, class: QosClass) -> Result<(), Unchanged>;
+    /// Fetch the quality of service class of this thread
+    ///
+    /// This is `None` if the thread hasn't been given a class.
+    fn qos(&self) -> Option<QosClass>;
+}
+
+impl ThreadExt for Thread {
+    fn set_qos(&mut self, class: QosClass) -> Result<(), Unchanged> {
+        self.0.set_qos(class)
+    }
+    fn qos(&self) -> Option<QosClass> {
+        self.0.qos()
+    }
+}
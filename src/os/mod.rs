@@ -0,0 +1,23 @@
+//! Platform-specific extensions.
+//!
+//! These expose details of the OS scheduling APIs that don't have an
+//! equivalent on every platform, so they're kept out of the portable API.
+
+#[cfg(target_os = "freebsd")]
+pub mod freebsd;
+#[cfg(target_os = "linux")]
+pub mod linux;
+#[cfg(target_os = "macos")]
+pub mod macos;
+#[cfg(unix)]
+pub mod unix;
+
+// Only the platform modules have traits to seal
+#[cfg(unix)]
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Priority {}
+    impl Sealed for crate::Process<'_> {}
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    impl Sealed for crate::Thread {}
+}
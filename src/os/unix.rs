@@ -0,0 +1,143 @@
+//! Unix-specific extensions.
+
+use crate::{NotFound, Priority, Process, Unchanged};
+
+/// Unix-specific extensions to [`Priority`].
+///
+/// Unix priorities are "niceness" values from `-20` (the highest priority)
+/// to `19` (the lowest), with `0` being [normal](Priority::normal).
+pub trait PriorityExt: super::private::Sealed {
+    /// Create a priority with the given niceness.
+    ///
+    /// The niceness is clamped into the range Unix supports.
+    ///
+    /// ```rust
+    /// use scrummage::{os::unix::PriorityExt, Priority};
+    /// assert_eq!(Priority::from_nice(10).nice(), 10);
+    /// assert_eq!(Priority::from_nice(100), Priority::lowest());
+    /// ```
+    fn from_nice(niceness: i32) -> Self;
+    /// The niceness of this priority.
+    fn nice(&self) -> i32;
+}
+
+impl PriorityExt for Priority {
+    fn from_nice(niceness: i32) -> Self {
+        Self(crate::imp::Priority::from_niceness(niceness))
+    }
+    fn nice(&self) -> i32 {
+        self.0.niceness()
+    }
+}
+
+/// Unix-specific extensions to [`Process`].
+pub trait ProcessExt: super::private::Sealed {
+    /// Refer to a process by a raw `pid_t`, such as one returned by `fork`.
+    ///
+    /// Like [`Process::from_pid`], the process isn't checked to exist.
+    /// Negative values never refer to a process.
+    ///
+    /// ```rust
+    /// use scrummage::{os::unix::ProcessExt, Process};
+    /// let me = Process::from_raw_pid(std::process::id() as i32);
+    /// assert_eq!(me.id(), std::process::id());
+    /// ```
+    fn from_raw_pid(pid: i32) -> Self;
+}
+
+impl ProcessExt for Process<'_> {
+    fn from_raw_pid(pid: i32) -> Self {
+        Process(crate::imp::Process::from_raw_pid(pid))
+    }
+}
+
+/// A group of processes, such as a shell pipeline.
+///
+/// Setting the priority of a group sets it for every process in it, which is
+/// handy for renicing a whole tree of workers at once.
+///
+/// ```rust
+/// use scrummage::{os::unix::ProcessGroup, Priority};
+/// use std::os::unix::process::CommandExt;
+///
+/// let mut leader = std::process::Command::new("sleep")
+///     .arg("1")
+///     .process_group(0)
+///     .spawn()
+///     .unwrap();
+/// let mut group = ProcessGroup::from_pgid(leader.id());
+/// let boring = Priority::normal().lower().next().unwrap();
+/// group.set_priority(boring).unwrap();
+/// assert_eq!(group.priority().unwrap(), boring);
+/// # leader.kill().unwrap();
+/// # leader.wait().unwrap();
+/// ```
+#[derive(Debug)]
+pub struct ProcessGroup(crate::imp::ProcessGroup);
+
+impl ProcessGroup {
+    /// Get the process group of the currently running process
+    pub fn current() -> Self {
+        Self(crate::imp::ProcessGroup::current())
+    }
+    /// Refer to a process group by its ID
+    ///
+    /// Like [`Process::from_pid`](crate::Process::from_pid), the group isn't
+    /// checked to exist.
+    pub fn from_pgid(pgid: u32) -> Self {
+        Self(crate::imp::ProcessGroup::from_pgid(pgid))
+    }
+    /// Update the priority of every process in this group
+    pub fn set_priority(&mut self, priority: Priority) -> Result<(), Unchanged> {
+        self.0.set_priority(priority.0)
+    }
+    /// Fetch the priority of this group
+    ///
+    /// This is the highest priority of any process in the group.
+    pub fn priority(&self) -> Result<Priority, NotFound> {
+        self.0.priority().map(Priority)
+    }
+}
+
+/// Every process owned by a user.
+///
+/// This is useful to deprioritise everything run by a batch user at once.
+/// Only root can raise the priority of a user's processes, and lowering it
+/// needs root too unless it's the current user.
+///
+/// ```rust,no_run
+/// use scrummage::{os::unix::UserProcesses, Priority};
+/// let mut batch = UserProcesses::from_uid(1001);
+/// batch.set_priority(Priority::lowest()).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct UserProcesses(crate::imp::UserProcesses);
+
+impl UserProcesses {
+    /// Get the processes owned by the user running this process
+    ///
+    /// ```rust
+    /// use scrummage::{os::unix::UserProcesses, Priority, Process};
+    /// let mine = UserProcesses::current();
+    /// assert!(mine.priority().unwrap() >= Process::current().priority().unwrap());
+    /// ```
+    pub fn current() -> Self {
+        Self(crate::imp::UserProcesses::current())
+    }
+    /// Refer to the processes of a user by their ID
+    ///
+    /// A user without any running processes gives [`NotFound`] errors.
+    pub fn from_uid(uid: u32) -> Self {
+        Self(crate::imp::UserProcesses::from_uid(uid))
+    }
+    /// Update the priority of every process owned by this user
+    pub fn set_priority(&mut self, priority: Priority) -> Result<(), Unchanged> {
+        self.0.set_priority(priority.0)
+    }
+    /// Fetch the priority of this user's processes
+    ///
+    /// This is the highest priority of any of them.
+    pub fn priority(&self) -> Result<Priority, NotFound> {
+        self.0.priority().map(Priority)
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{Priority, Process};
+
+/// A rule for choosing a priority, rather than the priority itself.
+///
+/// Some priorities only make sense relative to something else, like "one
+/// level below my own". A policy keeps that intent around, and
+/// [`resolve`](PriorityPolicy::resolve) works out the actual [`Priority`]
+/// each time it's needed, so it follows changes to our own priority.
+///
+/// ```rust
+/// # use scrummage::{Priority, PriorityPolicy, Process};
+/// let mut child = std::process::Command::new("sleep").arg("1").spawn().unwrap();
+/// let below_me = PriorityPolicy::RelativeToCurrent(1);
+/// let me = Process::current().priority().unwrap();
+/// assert_eq!(below_me.resolve(), me + 1);
+///
+/// Process::of_child(&mut child).apply_policy(below_me).unwrap();
+/// # child.kill().unwrap();
+/// # child.wait().unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PriorityPolicy {
+    /// Always this priority
+    Absolute(Priority),
+    /// This many levels below the priority of the current process, or above
+    /// it if negative. Like `priority + steps`, this stops at the highest and
+    /// lowest levels.
+    RelativeToCurrent(i32),
+    /// The [lowest](Priority::lowest) priority, for work that should only
+    /// run when nothing else wants to
+    Background,
+}
+
+impl PriorityPolicy {
+    /// Work out the priority this policy asks for right now
+    pub fn resolve(&self) -> Priority {
+        match *self {
+            Self::Absolute(priority) => priority,
+            Self::RelativeToCurrent(steps) => {
+                // The current process can't have gone away
+                let current = Process::current()
+                    .priority()
+                    .unwrap_or_else(|_| Priority::normal());
+                current + steps
+            }
+            Self::Background => Priority::lowest(),
+        }
+    }
+}
+
+impl From<Priority> for PriorityPolicy {
+    fn from(priority: Priority) -> Self {
+        Self::Absolute(priority)
+    }
+}
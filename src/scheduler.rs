@@ -0,0 +1,100 @@
+#[cfg(target_os = "linux")]
+use crate::{os::linux::IoPriority, CpuSet};
+use crate::{Priority, Process, Unchanged};
+
+/// A set of scheduling settings to apply to a process all at once.
+///
+/// Setting up background work usually means changing more than its
+/// priority. A `Scheduler` collects everything that should change, and
+/// [`apply`](Scheduler::apply) tries each setting even if another fails.
+///
+/// ```rust
+/// # use scrummage::{Priority, Process, Scheduler};
+/// let mut child = std::process::Command::new("sleep").arg("1").spawn().unwrap();
+/// let background = Scheduler::new().priority(Priority::lowest());
+/// # #[cfg(target_os = "linux")]
+/// let background = background.io_idle().affinity([0].iter().copied().collect());
+/// let applied = background.apply(&mut Process::of_child(&mut child));
+/// assert!(applied.is_ok());
+/// # child.kill().unwrap();
+/// # child.wait().unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Scheduler {
+    priority: Option<Priority>,
+    #[cfg(target_os = "linux")]
+    io_idle: bool,
+    #[cfg(target_os = "linux")]
+    affinity: Option<CpuSet>,
+}
+
+impl Scheduler {
+    /// Create a scheduler that doesn't change anything
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Set the [`Priority`] of the process
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+    /// Move the process to the idle I/O class, so it only uses the disk when
+    /// nothing else is
+    #[cfg(target_os = "linux")]
+    pub fn io_idle(mut self) -> Self {
+        self.io_idle = true;
+        self
+    }
+    /// Restrict the process to a set of cores
+    #[cfg(target_os = "linux")]
+    pub fn affinity(mut self, set: CpuSet) -> Self {
+        self.affinity = Some(set);
+        self
+    }
+    /// Apply every setting to `process`, reporting how each went
+    pub fn apply(&self, process: &mut Process<'_>) -> Applied {
+        Applied {
+            priority: self.priority.map(|priority| process.set_priority(priority)),
+            #[cfg(target_os = "linux")]
+            io_priority: if self.io_idle {
+                Some(process.0.set_io_priority(IoPriority::Idle))
+            } else {
+                None
+            },
+            #[cfg(target_os = "linux")]
+            affinity: self.affinity.map(|set| process.set_affinity(&set)),
+        }
+    }
+}
+
+/// How each setting of a [`Scheduler`] was applied.
+///
+/// Each field is `None` if the scheduler didn't change that setting. Some
+/// fields only exist on some platforms, and more may be added along with
+/// new settings.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct Applied {
+    /// The result of setting the priority
+    pub priority: Option<Result<(), Unchanged>>,
+    /// The result of setting the I/O class
+    #[cfg(target_os = "linux")]
+    pub io_priority: Option<Result<(), Unchanged>>,
+    /// The result of setting the affinity
+    #[cfg(target_os = "linux")]
+    pub affinity: Option<Result<(), Unchanged>>,
+}
+
+impl Applied {
+    /// Whether every setting was applied
+    pub fn is_ok(&self) -> bool {
+        let results = [
+            &self.priority,
+            #[cfg(target_os = "linux")]
+            &self.io_priority,
+            #[cfg(target_os = "linux")]
+            &self.affinity,
+        ];
+        results.iter().all(|result| !matches!(result, Some(Err(_))))
+    }
+}
@@ -0,0 +1,36 @@
+//! Priorities are stored on the [normalized](Priority::to_normalized) scale,
+//! so that they mean the same thing on every platform.
+
+use crate::Priority;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serialized as its [normalized](Priority::to_normalized) value.
+///
+/// ```rust
+/// # use scrummage::Priority;
+/// assert_eq!(serde_json::to_string(&Priority::normal()).unwrap(), "0.5");
+/// ```
+impl Serialize for Priority {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f32(self.to_normalized())
+    }
+}
+
+/// Deserialized with [`Priority::from_normalized`], picking the nearest level
+/// available on this platform.
+///
+/// ```rust
+/// # use scrummage::Priority;
+/// let config: Priority = serde_json::from_str("0.0").unwrap();
+/// assert_eq!(config, Priority::lowest());
+///
+/// for level in Priority::lowest().higher() {
+///     let json = serde_json::to_string(&level).unwrap();
+///     assert_eq!(serde_json::from_str::<Priority>(&json).unwrap(), level);
+/// }
+/// ```
+impl<'de> Deserialize<'de> for Priority {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        f32::deserialize(deserializer).map(Priority::from_normalized)
+    }
+}
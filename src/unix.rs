@@ -1,45 +1,122 @@
+use crate::NotSignalled;
 use crate::{Unchanged, NotFound};
-use libc::{getpid, getpriority, setpriority, PRIO_PROCESS};
+use libc::{getpid, getpriority, setpriority, PRIO_PGRP, PRIO_PROCESS, PRIO_USER};
+
+#[cfg(target_os = "freebsd")]
+use crate::os::freebsd::RtPrio;
+#[cfg(target_os = "linux")]
+use crate::os::linux::{IoPriority, SchedPolicy};
+#[cfg(target_os = "macos")]
+use crate::os::macos::QosClass;
 
 #[derive(Debug)]
 pub(crate) struct Process<'a> {
-    // FIXME: getpid returns an i32, but s/getpriority take a u32. What am I
-    // meant to store? I *think* the casts should retain the meaning anyway,
-    // but that should be checked.
-    pid: u32,
+    pid: libc::pid_t,
+    /// The last priority read, and when, for `Process::priority_cached`
+    #[cfg(feature = "std")]
+    pub cached: Option<(Priority, std::time::Instant)>,
     marker: core::marker::PhantomData<&'a ()>,
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub(crate) struct Priority {
     niceness: libc::c_int,
 }
 
+// Nicer processes have lower priorities, so the order is reversed
+impl Ord for Priority {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        other.niceness.cmp(&self.niceness)
+    }
+}
+impl PartialOrd for Priority {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl Priority {
-    pub fn higher(&self) -> impl Iterator<Item = Self> {
-        let mut niceness = self.niceness;
-        core::iter::from_fn(move || {
-            if niceness > -20 {
-                niceness -= 1;
-                Some(Self { niceness })
-            } else {
-                None
-            }
-        })
+    pub const fn highest() -> Self {
+        Self { niceness: -20 }
+    }
+    pub const fn lowest() -> Self {
+        Self { niceness: 19 }
+    }
+    pub const fn levels() -> usize {
+        (Self::lowest().niceness - Self::highest().niceness + 1) as usize
+    }
+    pub fn all() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        (Self::highest().niceness..Self::lowest().niceness + 1)
+            .rev()
+            .map(|niceness| Self { niceness })
+    }
+    pub fn from_niceness(niceness: libc::c_int) -> Self {
+        Self {
+            niceness: niceness.clamp(Self::highest().niceness, Self::lowest().niceness),
+        }
+    }
+    pub fn niceness(&self) -> libc::c_int {
+        self.niceness
     }
-    pub fn normal() -> Self {
+    pub fn as_step(self) -> i32 {
+        self.niceness - Self::normal().niceness
+    }
+    pub fn from_step(step: i32) -> Self {
+        Self::from_niceness(step.saturating_add(Self::normal().niceness))
+    }
+    pub fn from_normalized(scale: f32) -> Self {
+        // Round to the nearest step. `scale` is within `0.0..=1.0`, so
+        // truncating after adding a half is enough.
+        let steps =
+            |range: libc::c_int, fraction: f32| (range as f32 * fraction + 0.5) as libc::c_int;
+        let (lowest, highest) = (Self::lowest().niceness, Self::highest().niceness);
+        let niceness = if scale <= 0.5 {
+            lowest - steps(lowest, scale * 2.0)
+        } else {
+            -steps(-highest, scale * 2.0 - 1.0)
+        };
+        Self { niceness }
+    }
+    pub fn to_normalized(self) -> f32 {
+        let (lowest, highest) = (Self::lowest().niceness, Self::highest().niceness);
+        if self.niceness >= 0 {
+            (lowest - self.niceness) as f32 / lowest as f32 * 0.5
+        } else {
+            0.5 + self.niceness as f32 / highest as f32 * 0.5
+        }
+    }
+    pub fn is_realtime(self) -> bool {
+        // Real-time scheduling is a separate policy from niceness
+        false
+    }
+    pub fn is_elevated(self) -> bool {
+        self.niceness < Self::normal().niceness
+    }
+    pub fn is_idle(self) -> bool {
+        self == Self::lowest()
+    }
+    pub fn higher(&self) -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        (Self::highest().niceness..self.niceness)
+            .rev()
+            .map(|niceness| Self { niceness })
+    }
+    pub const fn normal() -> Self {
         Self { niceness: 0 }
     }
-    pub fn lower(&self) -> impl Iterator<Item = Self> {
-        let mut niceness = self.niceness;
-        core::iter::from_fn(move || {
-            if niceness < 19 {
-                niceness += 1;
-                Some(Self { niceness })
-            } else {
-                None
-            }
-        })
+    pub fn lower(&self) -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        (self.niceness + 1..Self::lowest().niceness + 1).map(|niceness| Self { niceness })
+    }
+}
+
+impl core::fmt::Debug for Priority {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "nice={}", self.niceness)
+    }
+}
+
+impl core::fmt::Display for Priority {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "niceness {}", self.niceness)
     }
 }
 
@@ -55,55 +132,799 @@ fn unexpected_err(errno: i32) -> ! {
         }
     })
 }
+fn errno_location() -> *mut libc::c_int {
+    // Safety: These all return the address of this thread's `errno`, under
+    // the name each libc gives the function
+    unsafe {
+        #[cfg(any(
+            target_os = "linux",
+            target_os = "emscripten",
+            target_os = "redox",
+            target_os = "dragonfly",
+            target_os = "hurd",
+        ))]
+        {
+            libc::__errno_location()
+        }
+        #[cfg(any(
+            target_os = "android",
+            target_os = "netbsd",
+            target_os = "openbsd",
+            target_os = "cygwin",
+        ))]
+        {
+            libc::__errno()
+        }
+        #[cfg(any(target_vendor = "apple", target_os = "freebsd"))]
+        {
+            libc::__error()
+        }
+        #[cfg(any(target_os = "solaris", target_os = "illumos"))]
+        {
+            libc::___errno()
+        }
+        #[cfg(target_os = "haiku")]
+        {
+            libc::_errnop()
+        }
+    }
+}
 fn errno() -> i32 {
-    // Safety: errno is thread-local, and __errno_location will
-    // always return a valid reference
-    unsafe { *libc::__errno_location() }
+    // Safety: errno is thread-local, so the reference is always valid
+    unsafe { *errno_location() }
 }
+// The type of the `PRIO_*` constants differs between libcs
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+type Which = libc::__priority_which_t;
+#[cfg(not(all(target_os = "linux", target_env = "gnu")))]
+type Which = libc::c_int;
+
+fn set_niceness(which: Which, who: libc::id_t, priority: Priority) -> Result<(), Unchanged> {
+    // Safety: `setpriority` checks its arguments
+    // Some bindings, like FreeBSD's, take `who` as a `c_int`
+    if unsafe { setpriority(which, who as _, priority.niceness) } == 0 {
+        Ok(())
+    } else {
+        match errno() {
+            libc::ESRCH => Err(Unchanged::NotFound(NotFound)),
+            // Linux only reports `EACCES` for raising a priority beyond
+            // the target's `RLIMIT_NICE` without `CAP_SYS_NICE`. Groups and
+            // users can mix limits, so there's no single one to report
+            #[cfg(target_os = "linux")]
+            libc::EACCES => Err(if which == PRIO_PROCESS {
+                rlimit_nice_ceiling(who as libc::pid_t)
+                    .map_or(Unchanged::PermissionDenied, |ceiling| {
+                        Unchanged::NiceLimit(crate::Priority(ceiling))
+                    })
+            } else {
+                Unchanged::PermissionDenied
+            }),
+            #[cfg(target_os = "linux")]
+            libc::EPERM => Err(Unchanged::PermissionDenied),
+            #[cfg(not(target_os = "linux"))]
+            libc::EACCES | libc::EPERM => Err(Unchanged::PermissionDenied),
+            errno => Err(Unchanged::Os(errno)),
+        }
+    }
+}
+fn niceness(which: Which, who: libc::id_t) -> Result<Priority, NotFound> {
+    // `getpriority` doesn't return an error code, so we need
+    // to reset `errno` in advance
+    unsafe {
+        // Safety: errno is thread-local, so the reference is always valid
+        *errno_location() = 0;
+    }
+    // Safety: `getpriority` checks its arguments
+    let niceness = unsafe { getpriority(which, who as _) };
+    match errno() {
+        0 => Ok(Priority { niceness }),
+        libc::ESRCH => Err(NotFound),
+        errno => unexpected_err(errno),
+    }
+}
+
+pub fn yield_now() {
+    // Safety: `sched_yield` is always safe to call. If it fails, we just
+    // carry on running
+    unsafe { libc::sched_yield() };
+}
+
+/// The highest priority the `RLIMIT_NICE` of `pid`, or of this process if
+/// it's 0, lets it be given without `CAP_SYS_NICE`
+///
+/// This is `None` if the limit couldn't be read, which needs the same user
+/// as the process.
+#[cfg(target_os = "linux")]
+fn rlimit_nice_ceiling(pid: libc::pid_t) -> Option<Priority> {
+    let mut limit = libc::rlimit {
+        rlim_cur: 0,
+        rlim_max: 0,
+    };
+    // Safety: `limit` is valid to write to, and a null new limit only reads
+    if unsafe { libc::prlimit(pid, libc::RLIMIT_NICE, core::ptr::null(), &mut limit) } != 0 {
+        return None;
+    }
+    // The limit is stored as `20 - niceness`, so that it's never negative
+    let limit = limit.rlim_cur.min(40) as libc::c_int;
+    Some(Priority::from_niceness(20 - limit))
+}
+
+/// Whether this process has the `CAP_SYS_NICE` capability
+#[cfg(all(target_os = "linux", feature = "std"))]
+fn has_cap_sys_nice() -> bool {
+    const CAP_SYS_NICE: u32 = 23;
+    let status = match std::fs::read_to_string("/proc/self/status") {
+        Ok(status) => status,
+        Err(_) => return false,
+    };
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("CapEff:"))
+        .and_then(|caps| u64::from_str_radix(caps.trim(), 16).ok())
+        .is_some_and(|caps| caps & 1 << CAP_SYS_NICE != 0)
+}
+
+#[cfg(all(target_os = "linux", feature = "std"))]
+pub fn can_raise_priority() -> bool {
+    has_cap_sys_nice()
+        || matches!(
+            (Process::current().priority(), rlimit_nice_ceiling(0)),
+            (Ok(current), Some(ceiling)) if ceiling > current
+        )
+}
+
+/// Convert a pid from the `u32` that std uses
+fn pid_from_u32(pid: u32) -> libc::pid_t {
+    // Real pids are much smaller than `pid_t::MAX`, so any larger value is
+    // equally missing
+    core::convert::TryFrom::try_from(pid).unwrap_or(libc::pid_t::MAX)
+}
+
 impl Process<'_> {
+    pub fn id(&self) -> u32 {
+        // pids are never negative
+        self.pid as u32
+    }
     pub fn current() -> Process<'static> {
+        // Safety: `getpid` is always safe to call
+        Process::from_raw_pid(unsafe { getpid() })
+    }
+    pub fn from_raw_pid(pid: libc::pid_t) -> Process<'static> {
         Process {
-            // Safety: `getpid` is always safe to call
-            pid: unsafe { getpid() } as u32,
+            // Negative pids refer to process groups in some APIs, like `kill`
+            pid: if pid < 0 { libc::pid_t::MAX } else { pid },
+            #[cfg(feature = "std")]
+            cached: None,
             marker: core::marker::PhantomData,
         }
     }
+    pub fn from_pid(pid: u32) -> Process<'static> {
+        Process::from_raw_pid(pid_from_u32(pid))
+    }
+    /// The pid to pass to syscalls, which read 0 as the caller or its whole
+    /// process group rather than a process of its own
+    fn target(&self) -> Result<libc::pid_t, NotFound> {
+        if self.pid == 0 {
+            Err(NotFound)
+        } else {
+            Ok(self.pid)
+        }
+    }
+    pub fn exists(&self) -> bool {
+        let pid = match self.target() {
+            Ok(pid) => pid,
+            Err(NotFound) => return false,
+        };
+        // Safety: signal 0 only checks whether the signal could be sent
+        unsafe { libc::kill(pid, 0) == 0 || errno() != libc::ESRCH }
+    }
     pub fn set_priority(&mut self, priority: Priority) -> Result<(), Unchanged> {
-        // Safety: `setpriority` checks its arguments
-        if unsafe { setpriority(PRIO_PROCESS, self.pid, priority.niceness) } == 0 {
+        set_niceness(PRIO_PROCESS, self.target()? as libc::id_t, priority)
+    }
+    pub fn priority(&self) -> Result<Priority, NotFound> {
+        niceness(PRIO_PROCESS, self.target()? as libc::id_t)
+    }
+    #[cfg(all(target_os = "linux", feature = "std"))]
+    pub fn base_priority(&self) -> Result<i32, NotFound> {
+        // The `priority` field, which is `20 + niceness` for normal
+        // processes and `-1 - sched_priority` for real-time ones
+        proc_stat_field(self.pid, 15).ok_or(NotFound)
+    }
+    #[cfg(not(all(target_os = "linux", feature = "std")))]
+    pub fn base_priority(&self) -> Result<i32, NotFound> {
+        // Match the `PRI` that `top` shows on Linux
+        Ok(self.priority()?.niceness + 20)
+    }
+    #[cfg(all(target_os = "linux", feature = "std"))]
+    pub fn highest_reachable(&self) -> Result<Option<Priority>, NotFound> {
+        let pid = self.target()?;
+        let current = self.priority()?;
+        if has_cap_sys_nice() {
+            return Ok(Some(Priority::highest()));
+        }
+        // Otherwise only processes whose real or effective user is our
+        // effective user can be changed at all
+        let (uid, euid) = proc_uids(pid).ok_or(NotFound)?;
+        // Safety: `geteuid` always succeeds
+        let caller = unsafe { libc::geteuid() };
+        if uid != caller && euid != caller {
+            return Ok(None);
+        }
+        // The kernel checks the target's limit, not ours
+        Ok(Some(
+            current.max(rlimit_nice_ceiling(pid).unwrap_or(current)),
+        ))
+    }
+    #[cfg(all(not(target_os = "linux"), feature = "std"))]
+    pub fn highest_reachable(&self) -> Result<Option<Priority>, NotFound> {
+        let current = self.priority()?;
+        // Safety: `geteuid` always succeeds
+        if unsafe { libc::geteuid() } == 0 {
+            return Ok(Some(Priority::highest()));
+        }
+        // There's no portable way to find the owner, but a process we can't
+        // signal belongs to another user, so its priority is out of reach
+        // Safety: signal 0 only checks whether the signal could be sent
+        if unsafe { libc::kill(self.target()?, 0) } != 0 && errno() == libc::EPERM {
+            return Ok(None);
+        }
+        Ok(Some(current))
+    }
+    pub fn terminate(&mut self) -> Result<(), NotSignalled> {
+        self.signal(libc::SIGTERM)
+    }
+    pub fn kill(&mut self) -> Result<(), NotSignalled> {
+        self.signal(libc::SIGKILL)
+    }
+    fn signal(&mut self, signal: libc::c_int) -> Result<(), NotSignalled> {
+        // Safety: `kill` checks its arguments
+        if unsafe { libc::kill(self.target()?, signal) } == 0 {
+            Ok(())
+        } else {
+            match errno() {
+                libc::ESRCH => Err(NotSignalled::NotFound(NotFound)),
+                libc::EPERM => Err(NotSignalled::PermissionDenied),
+                errno => Err(NotSignalled::Os(errno)),
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Process<'_> {
+    pub fn set_affinity(&mut self, set: &crate::CpuSet) -> Result<(), Unchanged> {
+        // Safety: `cpu_set_t` is a plain bitset
+        let mut raw: libc::cpu_set_t = unsafe { core::mem::zeroed() };
+        for cpu in set.iter() {
+            // Safety: `CpuSet::CAPACITY` is no more than `CPU_SETSIZE`
+            unsafe { libc::CPU_SET(cpu, &mut raw) };
+        }
+        let size = core::mem::size_of::<libc::cpu_set_t>();
+        // Safety: `raw` is a valid `cpu_set_t` of `size` bytes
+        if unsafe { libc::sched_setaffinity(self.target()?, size, &raw) } == 0 {
+            Ok(())
+        } else {
+            match errno() {
+                libc::ESRCH => Err(Unchanged::NotFound(NotFound)),
+                libc::EPERM => Err(Unchanged::PermissionDenied),
+                errno => Err(Unchanged::Os(errno)),
+            }
+        }
+    }
+    pub fn affinity(&self) -> Result<crate::CpuSet, NotFound> {
+        // Safety: `cpu_set_t` is a plain bitset
+        let mut raw: libc::cpu_set_t = unsafe { core::mem::zeroed() };
+        let size = core::mem::size_of::<libc::cpu_set_t>();
+        // Safety: `raw` is a valid `cpu_set_t` of `size` bytes
+        if unsafe { libc::sched_getaffinity(self.target()?, size, &mut raw) } == 0 {
+            Ok((0..crate::CpuSet::CAPACITY)
+                // Safety: `CpuSet::CAPACITY` is no more than `CPU_SETSIZE`
+                .filter(|&cpu| unsafe { libc::CPU_ISSET(cpu, &raw) })
+                .collect())
+        } else {
+            match errno() {
+                libc::ESRCH => Err(NotFound),
+                errno => unexpected_err(errno),
+            }
+        }
+    }
+}
+
+#[cfg(all(target_os = "linux", feature = "std"))]
+impl Process<'_> {
+    pub fn set_numa_node(&mut self, node: u16) -> Result<(), Unchanged> {
+        let path = format!("/sys/devices/system/node/node{}/cpulist", node);
+        let list = std::fs::read_to_string(path)
+            .map_err(|e| Unchanged::Os(e.raw_os_error().unwrap_or(libc::EIO)))?;
+        let set = parse_cpu_list(list.trim()).ok_or(Unchanged::Os(libc::EINVAL))?;
+        self.set_affinity(&set)
+    }
+    pub fn set_autogroup_nice(&mut self, nice: libc::c_int) -> Result<(), Unchanged> {
+        let nice = nice.clamp(Priority::highest().niceness, Priority::lowest().niceness);
+        let path = format!("/proc/{}/autogroup", self.pid);
+        std::fs::write(path, nice.to_string()).map_err(|e| match e.raw_os_error() {
+            Some(libc::ENOENT) => Unchanged::NotFound(NotFound),
+            Some(libc::EACCES) | Some(libc::EPERM) => Unchanged::PermissionDenied,
+            errno => Unchanged::Os(errno.unwrap_or(libc::EIO)),
+        })
+    }
+    pub fn autogroup_nice(&self) -> Result<libc::c_int, NotFound> {
+        let autogroup = std::fs::read_to_string(format!("/proc/{}/autogroup", self.pid))
+            .map_err(|_| NotFound)?;
+        // This looks like `/autogroup-123 nice 0`
+        autogroup
+            .split_whitespace()
+            .nth(2)
+            .and_then(|nice| nice.parse().ok())
+            .ok_or(NotFound)
+    }
+}
+
+/// Read a field of `/proc/<pid>/stat`, counting from the one after the
+/// command name
+#[cfg(all(target_os = "linux", feature = "std"))]
+fn proc_stat_field<T: core::str::FromStr>(pid: libc::pid_t, field: usize) -> Option<T> {
+    let stat = std::fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+    // The command name is in parentheses, and may contain anything,
+    // so skip past the last parenthesis before splitting the fields
+    let fields = &stat[stat.rfind(')')? + 1..];
+    fields.split_whitespace().nth(field)?.parse().ok()
+}
+
+/// The real and effective user of `pid`
+#[cfg(all(target_os = "linux", feature = "std"))]
+fn proc_uids(pid: libc::pid_t) -> Option<(libc::uid_t, libc::uid_t)> {
+    let status = std::fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+    // This looks like `Uid:\t1000\t1000\t1000\t1000`, starting with the
+    // real and effective users
+    let mut uids = status
+        .lines()
+        .find_map(|line| line.strip_prefix("Uid:"))?
+        .split_whitespace()
+        .map(|uid| uid.parse().ok());
+    Some((uids.next()??, uids.next()??))
+}
+
+/// Read the parent of a process from `/proc`
+#[cfg(all(target_os = "linux", feature = "std"))]
+fn proc_ppid(pid: libc::pid_t) -> Option<libc::pid_t> {
+    // Skip the process state to get to the parent's pid
+    proc_stat_field(pid, 1)
+}
+
+/// The pid of every process in `/proc`
+#[cfg(all(target_os = "linux", feature = "std"))]
+fn proc_pids() -> Result<impl Iterator<Item = libc::pid_t>, NotFound> {
+    let entries = std::fs::read_dir("/proc").map_err(|_| NotFound)?;
+    Ok(entries.filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok()))
+}
+
+/// Every process in `/proc`, along with its parent
+#[cfg(all(target_os = "linux", feature = "std"))]
+fn proc_tree() -> Result<Vec<(libc::pid_t, libc::pid_t)>, NotFound> {
+    Ok(proc_pids()?
+        // Processes can exit while we're looking
+        .filter_map(|pid| Some((pid, proc_ppid(pid)?)))
+        .collect())
+}
+
+/// Whether the command name of a process is `name`
+#[cfg(all(target_os = "linux", feature = "std"))]
+fn proc_comm_is(pid: libc::pid_t, name: &str) -> bool {
+    // The kernel cuts names down to `TASK_COMM_LEN - 1` bytes
+    const MAX_COMM: usize = 15;
+    let comm = match std::fs::read_to_string(format!("/proc/{}/comm", pid)) {
+        Ok(comm) => comm,
+        Err(_) => return false,
+    };
+    let comm = comm.strip_suffix('\n').unwrap_or(&comm);
+    comm == name || (comm.len() == MAX_COMM && name.as_bytes().starts_with(comm.as_bytes()))
+}
+
+#[cfg(all(target_os = "linux", feature = "std"))]
+impl Process<'_> {
+    pub fn find_by_name(name: &str) -> Vec<Process<'static>> {
+        match proc_pids() {
+            Ok(pids) => pids
+                .filter(|&pid| proc_comm_is(pid, name))
+                .map(Process::from_raw_pid)
+                .collect(),
+            Err(NotFound) => vec![],
+        }
+    }
+    pub fn parent(&self) -> Result<Process<'static>, NotFound> {
+        // Safety: `getpid` and `getppid` are always safe to call
+        let ppid = if self.pid == unsafe { getpid() } {
+            unsafe { libc::getppid() }
+        } else {
+            proc_ppid(self.pid).ok_or(NotFound)?
+        };
+        // The first process has no parent
+        if ppid == 0 {
+            return Err(NotFound);
+        }
+        Ok(Process::from_raw_pid(ppid))
+    }
+    pub fn children(&self) -> Result<Vec<Process<'static>>, NotFound> {
+        self.find_children(false)
+    }
+    pub fn descendants(&self) -> Result<Vec<Process<'static>>, NotFound> {
+        self.find_children(true)
+    }
+    /// Find the children of this process, and their children too if
+    /// `recurse` is set
+    fn find_children(&self, recurse: bool) -> Result<Vec<Process<'static>>, NotFound> {
+        if !self.exists() {
+            return Err(NotFound);
+        }
+        let tree = proc_tree()?;
+        let mut found = vec![];
+        let mut parents = vec![self.pid];
+        while let Some(parent) = parents.pop() {
+            for &(pid, ppid) in &tree {
+                if ppid == parent {
+                    found.push(Process::from_raw_pid(pid));
+                    if recurse {
+                        parents.push(pid);
+                    }
+                }
+            }
+        }
+        Ok(found)
+    }
+}
+
+/// Parse the kernel's list format, like `0-3,8,10-11`
+#[cfg(all(target_os = "linux", feature = "std"))]
+fn parse_cpu_list(list: &str) -> Option<crate::CpuSet> {
+    let mut set = crate::CpuSet::new();
+    for range in list.split(',').filter(|range| !range.is_empty()) {
+        let (start, end) = match range.find('-') {
+            Some(dash) => (&range[..dash], &range[dash + 1..]),
+            None => (range, range),
+        };
+        let (start, end): (usize, usize) = (start.parse().ok()?, end.parse().ok()?);
+        if end >= crate::CpuSet::CAPACITY {
+            return None;
+        }
+        set.extend(start..=end);
+    }
+    Some(set)
+}
+
+#[cfg(target_os = "linux")]
+impl Process<'_> {
+    pub fn set_sched_policy(&mut self, policy: SchedPolicy) -> Result<(), Unchanged> {
+        let (policy, priority) = match policy {
+            SchedPolicy::Other => (libc::SCHED_OTHER, 0),
+            SchedPolicy::Batch => (libc::SCHED_BATCH, 0),
+            SchedPolicy::Idle => (libc::SCHED_IDLE, 0),
+            SchedPolicy::Fifo(priority) => (libc::SCHED_FIFO, priority.clamp(1, 99)),
+            SchedPolicy::RoundRobin(priority) => (libc::SCHED_RR, priority.clamp(1, 99)),
+            SchedPolicy::Deadline => (libc::SCHED_DEADLINE, 0),
+            SchedPolicy::Unknown(policy) => (policy, 0),
+        };
+        let param = libc::sched_param {
+            sched_priority: priority.into(),
+        };
+        // Safety: `param` is a valid `sched_param`
+        if unsafe { libc::sched_setscheduler(self.target()?, policy, &param) } == 0 {
             Ok(())
         } else {
             match errno() {
                 libc::ESRCH => Err(Unchanged::NotFound(NotFound)),
-                libc::EACCES | libc::EPERM => Err(Unchanged::PermissionDenied),
+                libc::EPERM => Err(Unchanged::PermissionDenied),
+                errno => Err(Unchanged::Os(errno)),
+            }
+        }
+    }
+    pub fn sched_policy(&self) -> Result<SchedPolicy, NotFound> {
+        let pid = self.target()?;
+        // Safety: `sched_getscheduler` checks its arguments
+        let policy = unsafe { libc::sched_getscheduler(pid) };
+        if policy == -1 {
+            return match errno() {
+                libc::ESRCH => Err(NotFound),
                 errno => unexpected_err(errno),
+            };
+        }
+        let rt_priority = || {
+            let mut param = libc::sched_param { sched_priority: 0 };
+            // Safety: `param` is a valid `sched_param`
+            if unsafe { libc::sched_getparam(pid, &mut param) } == 0 {
+                Ok(param.sched_priority as u8)
+            } else {
+                match errno() {
+                    libc::ESRCH => Err(NotFound),
+                    errno => unexpected_err(errno),
+                }
             }
+        };
+        match policy & !libc::SCHED_RESET_ON_FORK {
+            libc::SCHED_OTHER => Ok(SchedPolicy::Other),
+            libc::SCHED_BATCH => Ok(SchedPolicy::Batch),
+            libc::SCHED_IDLE => Ok(SchedPolicy::Idle),
+            libc::SCHED_FIFO => rt_priority().map(SchedPolicy::Fifo),
+            libc::SCHED_RR => rt_priority().map(SchedPolicy::RoundRobin),
+            libc::SCHED_DEADLINE => Ok(SchedPolicy::Deadline),
+            policy => Ok(SchedPolicy::Unknown(policy)),
         }
     }
+}
+
+// From linux/ioprio.h
+#[cfg(target_os = "linux")]
+mod ioprio {
+    pub const WHO_PROCESS: libc::c_int = 1;
+    pub const CLASS_SHIFT: libc::c_int = 13;
+    // Newer kernels keep hints in the bits between the level and the class
+    pub const LEVEL_MASK: libc::c_int = 0x7;
+    pub const CLASS_NONE: libc::c_int = 0;
+    pub const CLASS_RT: libc::c_int = 1;
+    pub const CLASS_BE: libc::c_int = 2;
+    pub const CLASS_IDLE: libc::c_int = 3;
+}
+
+#[cfg(target_os = "linux")]
+impl Process<'_> {
+    pub fn set_io_priority(&mut self, priority: IoPriority) -> Result<(), Unchanged> {
+        let (class, level) = match priority {
+            IoPriority::RealTime(level) => (ioprio::CLASS_RT, level.min(7)),
+            IoPriority::BestEffort(level) => (ioprio::CLASS_BE, level.min(7)),
+            IoPriority::Idle => (ioprio::CLASS_IDLE, 0),
+        };
+        self.set_raw_io_priority(class << ioprio::CLASS_SHIFT | libc::c_int::from(level))
+    }
+    pub fn reset_io_priority(&mut self) -> Result<(), Unchanged> {
+        // Go back to deriving the I/O priority from the niceness
+        self.set_raw_io_priority(ioprio::CLASS_NONE << ioprio::CLASS_SHIFT)
+    }
+    fn set_raw_io_priority(&mut self, ioprio: libc::c_int) -> Result<(), Unchanged> {
+        let pid = self.target()?;
+        // Safety: `ioprio_set` checks its arguments
+        let result =
+            unsafe { libc::syscall(libc::SYS_ioprio_set, ioprio::WHO_PROCESS, pid, ioprio) };
+        if result == 0 {
+            Ok(())
+        } else {
+            match errno() {
+                libc::ESRCH => Err(Unchanged::NotFound(NotFound)),
+                libc::EPERM => Err(Unchanged::PermissionDenied),
+                errno => Err(Unchanged::Os(errno)),
+            }
+        }
+    }
+    pub fn io_priority(&self) -> Result<IoPriority, NotFound> {
+        let pid = self.target()?;
+        // Safety: `ioprio_get` checks its arguments
+        let ioprio = unsafe { libc::syscall(libc::SYS_ioprio_get, ioprio::WHO_PROCESS, pid) };
+        if ioprio == -1 {
+            return match errno() {
+                libc::ESRCH => Err(NotFound),
+                errno => unexpected_err(errno),
+            };
+        }
+        let ioprio = ioprio as libc::c_int;
+        let level = (ioprio & ioprio::LEVEL_MASK) as u8;
+        match ioprio >> ioprio::CLASS_SHIFT {
+            ioprio::CLASS_RT => Ok(IoPriority::RealTime(level)),
+            ioprio::CLASS_BE => Ok(IoPriority::BestEffort(level)),
+            ioprio::CLASS_IDLE => Ok(IoPriority::Idle),
+            // The kernel derives the level from the niceness, see
+            // `task_nice_ioprio`
+            ioprio::CLASS_NONE => {
+                let niceness = self.priority()?.niceness;
+                Ok(IoPriority::BestEffort(((niceness + 20) / 5) as u8))
+            }
+            class => unreachable!("unknown I/O scheduling class: {}", class),
+        }
+    }
+}
+
+/// `RTP_PRIO_REALTIME | RTP_PRIO_FIFO_BIT`, which libc doesn't define
+#[cfg(target_os = "freebsd")]
+const RTP_PRIO_FIFO: libc::c_ushort = 10;
+
+#[cfg(target_os = "freebsd")]
+impl Process<'_> {
+    pub fn set_rtprio(&mut self, rtprio: RtPrio) -> Result<(), Unchanged> {
+        let (type_, prio) = match rtprio {
+            RtPrio::RealTime(prio) => (libc::RTP_PRIO_REALTIME, prio),
+            RtPrio::Fifo(prio) => (RTP_PRIO_FIFO, prio),
+            RtPrio::Normal => (libc::RTP_PRIO_NORMAL, 0),
+            RtPrio::Idle(prio) => (libc::RTP_PRIO_IDLE, prio),
+            RtPrio::Unknown(class) => (class, 0),
+        };
+        let mut rtp = libc::rtprio {
+            type_,
+            prio: libc::c_ushort::from(prio).min(libc::RTP_PRIO_MAX),
+        };
+        // Safety: `rtp` is a valid `rtprio`
+        if unsafe { libc::rtprio(libc::RTP_SET, self.target()?, &mut rtp) } == 0 {
+            Ok(())
+        } else {
+            match errno() {
+                libc::ESRCH => Err(Unchanged::NotFound(NotFound)),
+                libc::EPERM => Err(Unchanged::PermissionDenied),
+                errno => Err(Unchanged::Os(errno)),
+            }
+        }
+    }
+    pub fn rtprio(&self) -> Result<RtPrio, NotFound> {
+        let mut rtp = libc::rtprio { type_: 0, prio: 0 };
+        // Safety: `rtp` is valid to write to
+        if unsafe { libc::rtprio(libc::RTP_LOOKUP, self.target()?, &mut rtp) } != 0 {
+            return match errno() {
+                libc::ESRCH => Err(NotFound),
+                errno => unexpected_err(errno),
+            };
+        }
+        // The level is at most `RTP_PRIO_MAX`
+        let prio = rtp.prio as u8;
+        match rtp.type_ {
+            libc::RTP_PRIO_REALTIME => Ok(RtPrio::RealTime(prio)),
+            libc::RTP_PRIO_NORMAL => Ok(RtPrio::Normal),
+            libc::RTP_PRIO_IDLE => Ok(RtPrio::Idle(prio)),
+            RTP_PRIO_FIFO => Ok(RtPrio::Fifo(prio)),
+            class => Ok(RtPrio::Unknown(class)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ProcessGroup {
+    pgid: libc::pid_t,
+}
+
+impl ProcessGroup {
+    pub fn current() -> Self {
+        Self {
+            // Safety: `getpgrp` is always safe to call
+            pgid: unsafe { libc::getpgrp() },
+        }
+    }
+    pub fn from_pgid(pgid: u32) -> Self {
+        Self {
+            pgid: pid_from_u32(pgid),
+        }
+    }
+    pub fn set_priority(&mut self, priority: Priority) -> Result<(), Unchanged> {
+        set_niceness(PRIO_PGRP, self.pgid as libc::id_t, priority)
+    }
     pub fn priority(&self) -> Result<Priority, NotFound> {
-        // `getpriority` doesn't return an error code, so we need
-        // to reset `errno` in advance
-        unsafe {
-            // Safety: errno is thread-local, and __errno_location will
-            // always return a valid reference
-            *libc::__errno_location() = 0;
-        }
-        // Safety: `getpriority` checks its arguments
-        let niceness = unsafe { getpriority(PRIO_PROCESS, self.pid) };
-        match errno() {
-            0 => Ok(Priority { niceness }),
-            libc::ESRCH => Err(NotFound),
-            errno => unexpected_err(errno),
+        niceness(PRIO_PGRP, self.pgid as libc::id_t)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct UserProcesses {
+    uid: libc::uid_t,
+}
+
+impl UserProcesses {
+    pub fn current() -> Self {
+        Self {
+            // Safety: `getuid` is always safe to call
+            uid: unsafe { libc::getuid() },
         }
     }
+    pub fn from_uid(uid: u32) -> Self {
+        Self { uid: uid as _ }
+    }
+    pub fn set_priority(&mut self, priority: Priority) -> Result<(), Unchanged> {
+        set_niceness(PRIO_USER, self.uid as libc::id_t, priority)
+    }
+    pub fn priority(&self) -> Result<Priority, NotFound> {
+        niceness(PRIO_USER, self.uid as libc::id_t)
+    }
 }
 
-#[cfg(feature = "std")]
-impl<'a> From<&'a mut std::process::Child> for Process<'a> {
-    fn from(child: &mut std::process::Child) -> Self {
+#[cfg(target_os = "linux")]
+#[derive(Debug)]
+pub(crate) struct Thread {
+    tid: libc::pid_t,
+    // Thread IDs are reused once the thread exits, so this must stay on the
+    // thread it refers to
+    marker: core::marker::PhantomData<*const ()>,
+}
+
+#[cfg(target_os = "linux")]
+impl Thread {
+    pub fn current() -> Self {
+        Self {
+            // Safety: `gettid` is always safe to call
+            tid: unsafe { libc::syscall(libc::SYS_gettid) } as libc::pid_t,
+            marker: core::marker::PhantomData,
+        }
+    }
+    pub fn id(&self) -> u32 {
+        // tids are never negative
+        self.tid as u32
+    }
+    pub fn set_priority(&mut self, priority: Priority) -> Result<(), Unchanged> {
+        // Linux applies `PRIO_PROCESS` to individual threads
+        set_niceness(PRIO_PROCESS, self.tid as libc::id_t, priority)
+    }
+    pub fn priority(&self) -> Result<Priority, NotFound> {
+        niceness(PRIO_PROCESS, self.tid as libc::id_t)
+    }
+}
+
+#[cfg(target_os = "macos")]
+#[derive(Debug)]
+pub(crate) struct Thread {
+    // QoS classes can only be set by the thread itself
+    marker: core::marker::PhantomData<*const ()>,
+}
+
+#[cfg(target_os = "macos")]
+impl Thread {
+    pub fn current() -> Self {
         Self {
-            pid: child.id() as u32,
             marker: core::marker::PhantomData,
         }
     }
+    pub fn set_qos(&mut self, class: QosClass) -> Result<(), Unchanged> {
+        let class = match class {
+            QosClass::Background => libc::qos_class_t::QOS_CLASS_BACKGROUND,
+            QosClass::Utility => libc::qos_class_t::QOS_CLASS_UTILITY,
+            QosClass::Default => libc::qos_class_t::QOS_CLASS_DEFAULT,
+            QosClass::UserInitiated => libc::qos_class_t::QOS_CLASS_USER_INITIATED,
+            QosClass::UserInteractive => libc::qos_class_t::QOS_CLASS_USER_INTERACTIVE,
+        };
+        // Safety: This only affects the calling thread, which is the one
+        // `self` refers to
+        match unsafe { libc::pthread_set_qos_class_self_np(class, 0) } {
+            0 => Ok(()),
+            libc::EPERM => Err(Unchanged::PermissionDenied),
+            errno => Err(Unchanged::Os(errno)),
+        }
+    }
+    pub fn qos(&self) -> Option<QosClass> {
+        let mut class = libc::qos_class_t::QOS_CLASS_UNSPECIFIED;
+        let mut relative_priority = 0;
+        // Safety: `pthread_self` is always a valid thread, and both outputs
+        // are valid to write to
+        let err = unsafe {
+            libc::pthread_get_qos_class_np(libc::pthread_self(), &mut class, &mut relative_priority)
+        };
+        if err != 0 {
+            unexpected_err(err)
+        }
+        match class {
+            libc::qos_class_t::QOS_CLASS_BACKGROUND => Some(QosClass::Background),
+            libc::qos_class_t::QOS_CLASS_UTILITY => Some(QosClass::Utility),
+            libc::qos_class_t::QOS_CLASS_DEFAULT => Some(QosClass::Default),
+            libc::qos_class_t::QOS_CLASS_USER_INITIATED => Some(QosClass::UserInitiated),
+            libc::qos_class_t::QOS_CLASS_USER_INTERACTIVE => Some(QosClass::UserInteractive),
+            libc::qos_class_t::QOS_CLASS_UNSPECIFIED => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+pub fn spawn_with_priority(
+    mut command: std::process::Command,
+    priority: Priority,
+) -> std::io::Result<std::process::Child> {
+    use std::os::unix::process::CommandExt;
+    // Safety: `setpriority` is async-signal-safe, and building the error
+    // from errno doesn't allocate
+    unsafe {
+        command.pre_exec(move || {
+            if setpriority(PRIO_PROCESS, 0, priority.niceness) == 0 {
+                Ok(())
+            } else {
+                Err(std::io::Error::last_os_error())
+            }
+        });
+    }
+    command.spawn()
+}
+
+#[cfg(feature = "std")]
+impl<'a> From<&'a std::process::Child> for Process<'a> {
+    fn from(child: &std::process::Child) -> Self {
+        Process::from_pid(child.id())
+    }
 }
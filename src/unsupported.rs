@@ -0,0 +1,129 @@
+//! A stand-in for platforms without any priorities scrummage knows about,
+//! such as `wasm32-unknown-unknown`.
+//!
+//! There's a single, normal, priority level. Every process is assumed to
+//! have it, and trying to change anything reports [`Unchanged::Unsupported`].
+
+use crate::{NotFound, NotSignalled, Unchanged};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct Priority;
+
+impl Priority {
+    pub const fn highest() -> Self {
+        Self
+    }
+    pub const fn lowest() -> Self {
+        Self
+    }
+    pub const fn normal() -> Self {
+        Self
+    }
+    pub const fn levels() -> usize {
+        1
+    }
+    pub fn all() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        core::iter::once(Self)
+    }
+    pub fn from_normalized(_scale: f32) -> Self {
+        Self
+    }
+    pub fn to_normalized(self) -> f32 {
+        0.5
+    }
+    pub fn as_step(self) -> i32 {
+        0
+    }
+    pub fn from_step(_step: i32) -> Self {
+        Self
+    }
+    pub fn is_realtime(self) -> bool {
+        false
+    }
+    pub fn is_elevated(self) -> bool {
+        false
+    }
+    pub fn is_idle(self) -> bool {
+        // The only level is also the lowest
+        true
+    }
+    pub fn higher(&self) -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        core::iter::empty()
+    }
+    pub fn lower(&self) -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        core::iter::empty()
+    }
+}
+
+impl core::fmt::Display for Priority {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("normal")
+    }
+}
+
+pub fn yield_now() {}
+
+#[derive(Debug)]
+pub(crate) struct Process<'a> {
+    pid: u32,
+    #[cfg(feature = "std")]
+    pub cached: Option<(Priority, std::time::Instant)>,
+    marker: core::marker::PhantomData<&'a ()>,
+}
+
+impl Process<'_> {
+    pub fn id(&self) -> u32 {
+        self.pid
+    }
+    pub fn current() -> Self {
+        // There's no way to find out our own ID
+        Self::from_pid(0)
+    }
+    pub fn from_pid(pid: u32) -> Self {
+        Self {
+            pid,
+            #[cfg(feature = "std")]
+            cached: None,
+            marker: core::marker::PhantomData,
+        }
+    }
+    pub fn exists(&self) -> bool {
+        // Without a way to check, assume the caller knows best
+        true
+    }
+    pub fn set_priority(&mut self, _priority: Priority) -> Result<(), Unchanged> {
+        Err(Unchanged::Unsupported)
+    }
+    pub fn priority(&self) -> Result<Priority, NotFound> {
+        Ok(Priority)
+    }
+    pub fn base_priority(&self) -> Result<i32, NotFound> {
+        Ok(0)
+    }
+    #[cfg(feature = "std")]
+    pub fn highest_reachable(&self) -> Result<Option<Priority>, NotFound> {
+        Ok(Some(Priority))
+    }
+    pub fn terminate(&mut self) -> Result<(), NotSignalled> {
+        Err(NotSignalled::Unsupported)
+    }
+    pub fn kill(&mut self) -> Result<(), NotSignalled> {
+        Err(NotSignalled::Unsupported)
+    }
+}
+
+#[cfg(feature = "std")]
+pub fn spawn_with_priority(
+    mut command: std::process::Command,
+    _priority: Priority,
+) -> std::io::Result<std::process::Child> {
+    // The child already has the only priority there is
+    command.spawn()
+}
+
+#[cfg(feature = "std")]
+impl<'a> From<&'a std::process::Child> for Process<'a> {
+    fn from(child: &std::process::Child) -> Self {
+        Self::from_pid(child.id())
+    }
+}
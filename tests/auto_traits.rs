@@ -0,0 +1,73 @@
+//! The thread-safety every type promises, so it can't change by accident.
+
+use scrummage::{
+    Applied, CpuSet, NotFound, NotSignalled, OutOfRange, Priority, PriorityGuard, PriorityLevel,
+    PriorityPolicy, Process, Scheduler, Unchanged, UnknownLevel,
+};
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn shareable_between_threads() {
+    assert_send_sync::<Priority>();
+    assert_send_sync::<PriorityLevel>();
+    assert_send_sync::<PriorityPolicy>();
+    assert_send_sync::<Process<'static>>();
+    assert_send_sync::<PriorityGuard<'static, 'static>>();
+    assert_send_sync::<CpuSet>();
+    assert_send_sync::<Scheduler>();
+    assert_send_sync::<Applied>();
+    assert_send_sync::<NotFound>();
+    assert_send_sync::<OutOfRange>();
+    assert_send_sync::<UnknownLevel>();
+    assert_send_sync::<Unchanged>();
+    assert_send_sync::<NotSignalled>();
+}
+
+#[cfg(unix)]
+#[test]
+fn unix_shareable_between_threads() {
+    use scrummage::os::unix::{ProcessGroup, UserProcesses};
+    assert_send_sync::<ProcessGroup>();
+    assert_send_sync::<UserProcesses>();
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn linux_shareable_between_threads() {
+    use scrummage::os::linux::{IoPriority, SchedPolicy};
+    assert_send_sync::<SchedPolicy>();
+    assert_send_sync::<IoPriority>();
+}
+
+#[cfg(target_os = "macos")]
+#[test]
+fn macos_shareable_between_threads() {
+    assert_send_sync::<scrummage::os::macos::QosClass>();
+}
+
+#[cfg(target_os = "freebsd")]
+#[test]
+fn freebsd_shareable_between_threads() {
+    assert_send_sync::<scrummage::os::freebsd::RtPrio>();
+}
+
+/// Only implemented once for `T: Send`, so naming the implementation with
+/// an inferred parameter is ambiguous, and fails to compile, for `Send`
+/// types. This is the trick `static_assertions` uses.
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+trait AmbiguousIfSend<A> {
+    fn some_item() {}
+}
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+impl<T: ?Sized> AmbiguousIfSend<()> for T {}
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+impl<T: ?Sized + Send> AmbiguousIfSend<u8> for T {}
+
+/// A `Thread` refers to the thread it was made on, whose ID the OS can reuse
+/// once it exits, so it must stay `!Send`.
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+#[test]
+fn thread_stays_on_its_thread() {
+    <scrummage::Thread as AmbiguousIfSend<_>>::some_item();
+}
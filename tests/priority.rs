@@ -0,0 +1,122 @@
+//! Check that priorities actually change on real child processes.
+
+use scrummage::{NotSignalled, Priority, Process, Unchanged};
+use std::process::{Child, Command};
+
+/// A child process that's killed when dropped, even if the test fails
+struct Sleeper(Child);
+
+impl Sleeper {
+    fn spawn() -> Self {
+        Self(Command::new("sleep").arg("10").spawn().unwrap())
+    }
+}
+
+impl Drop for Sleeper {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+#[test]
+fn lowering_round_trips() {
+    let mut child = Sleeper::spawn();
+    let mut process = Process::of_child(&mut child.0);
+    assert_eq!(process.priority().unwrap(), Priority::normal());
+    // Lowering a priority never needs privileges, so every level must work
+    for priority in Priority::normal().lower() {
+        process.set_priority(priority).unwrap();
+        assert_eq!(process.priority().unwrap(), priority);
+    }
+}
+
+#[test]
+fn raising_round_trips_unless_denied() {
+    for priority in Priority::normal().higher() {
+        let mut child = Sleeper::spawn();
+        let mut process = Process::of_child(&mut child.0);
+        match process.set_priority(priority) {
+            Ok(()) => assert_eq!(process.priority().unwrap(), priority),
+            // Raising a priority usually needs privileges
+            Err(Unchanged::PermissionDenied) | Err(Unchanged::NiceLimit(_)) => {}
+            Err(e) => panic!("couldn't set {}: {}", priority, e),
+        }
+    }
+}
+
+#[test]
+fn reachable_priorities_can_be_set() {
+    let mut child = Sleeper::spawn();
+    let mut process = Process::of_child(&mut child.0);
+    process.set_priority(Priority::lowest()).unwrap();
+    let reachable = process.reachable_priorities().unwrap();
+    assert_eq!(reachable.first(), Some(&Priority::lowest()));
+    // Going up from the lowest, exactly the reachable levels are allowed
+    for priority in Priority::all() {
+        let result = process.set_priority(priority);
+        assert_eq!(
+            result.is_ok(),
+            reachable.contains(&priority),
+            "{}",
+            priority
+        );
+    }
+}
+
+#[test]
+fn exited_process_is_not_found() {
+    let mut child = Command::new("true").spawn().unwrap();
+    let id = child.id();
+    child.wait().unwrap();
+    let mut process = Process::from_pid(id);
+    assert!(process.priority().is_err());
+    assert!(matches!(
+        process.set_priority(Priority::lowest()),
+        Err(Unchanged::NotFound(_))
+    ));
+}
+
+#[test]
+fn pid_zero_is_not_found() {
+    // 0 means the caller, or its whole process group, to the syscalls
+    let mut process = Process::from_pid(0);
+    assert_eq!(process.id(), 0);
+    assert_ne!(process, Process::from_pid(u32::MAX));
+    assert!(!process.exists());
+    assert!(process.priority().is_err());
+    assert_ne!(process, Process::current());
+    assert!(matches!(
+        process.set_priority(Priority::lowest()),
+        Err(Unchanged::NotFound(_))
+    ));
+    assert!(matches!(
+        process.terminate(),
+        Err(NotSignalled::NotFound(_))
+    ));
+}
+
+#[test]
+fn signal_errors_describe_signalling() {
+    let mut child = Command::new("true").spawn().unwrap();
+    let id = child.id();
+    child.wait().unwrap();
+    let e = std::io::Error::from(Process::from_pid(id).kill().unwrap_err());
+    assert_eq!(e.kind(), std::io::ErrorKind::NotFound);
+    assert_eq!(e.to_string(), "couldn't signal missing process");
+}
+
+#[test]
+fn spawn_with_priority_sets_each_child() {
+    // Each spawn has its own command, so only its own priority applies:
+    // going up from the lowest would fail unprivileged otherwise
+    for priority in Priority::normal().lower().rev() {
+        let mut command = Command::new("sleep");
+        command.arg("10");
+        let mut child = Sleeper(scrummage::spawn_with_priority(command, priority).unwrap());
+        assert_eq!(
+            Process::of_child(&mut child.0).priority().unwrap(),
+            priority
+        );
+    }
+}